@@ -0,0 +1,96 @@
+//! Generates the rows of the ISO 4217 table (`src/iso4217.rs`) from `data/iso4217.csv`.
+//!
+//! Each CSV row is `code,numeric,minor_units,name`, taken from ISO 4217 list one. Only
+//! currencies with a defined number of minor units are listed, and names are ASCII. To update
+//! the table, edit the CSV; the rows are validated and sorted here.
+
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const SOURCE: &str = "data/iso4217.csv";
+
+struct Row {
+    code: String,
+    numeric: u16,
+    minor_units: u8,
+    name: String,
+}
+
+fn parse_row(line_number: usize, line: &str) -> Row {
+    let fail = |reason: &str| -> ! { panic!("{SOURCE}:{line_number}: {reason}: {line:?}") };
+
+    let fields: Vec<&str> = line.splitn(4, ',').map(str::trim).collect();
+    let [code, numeric, minor_units, name] = fields[..] else {
+        fail("expected code,numeric,minor_units,name");
+    };
+    if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_uppercase()) {
+        fail("code must be three uppercase letters");
+    }
+    let numeric: u16 = numeric
+        .parse()
+        .unwrap_or_else(|_| fail("numeric code must be a number"));
+    if !(1..=999).contains(&numeric) {
+        fail("numeric code must be between 1 and 999");
+    }
+    let minor_units: u8 = minor_units
+        .parse()
+        .unwrap_or_else(|_| fail("minor units must be a number"));
+    if minor_units > 28 {
+        fail("minor units must be at most 28");
+    }
+    if name.is_empty() || !name.is_ascii() || name.len() > 52 {
+        fail("name must be non-empty ASCII of at most 52 bytes");
+    }
+
+    Row {
+        code: code.to_string(),
+        numeric,
+        minor_units,
+        name: name.to_string(),
+    }
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed={SOURCE}");
+
+    let input = fs::read_to_string(SOURCE).unwrap_or_else(|e| panic!("{SOURCE}: {e}"));
+    let mut rows: Vec<Row> = input
+        .lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse_row(index + 1, line))
+        .collect();
+
+    // `FinMoneyCurrency::from_iso_alpha` relies on the order for binary search.
+    rows.sort_by(|a, b| a.code.cmp(&b.code));
+    let mut codes = HashSet::new();
+    let mut numerics = HashSet::new();
+    for row in &rows {
+        assert!(
+            codes.insert(&row.code),
+            "{SOURCE}: duplicate code {}",
+            row.code
+        );
+        assert!(
+            numerics.insert(row.numeric),
+            "{SOURCE}: duplicate numeric code {}",
+            row.numeric
+        );
+    }
+
+    let mut table = String::from("iso4217_table! {\n");
+    for row in &rows {
+        table.push_str(&format!(
+            "    {} => ({}, {}, {:?}),\n",
+            row.code, row.numeric, row.minor_units, row.name
+        ));
+    }
+    table.push_str("}\n");
+
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("iso4217_table.rs"), table)
+        .expect("failed to write the generated ISO 4217 table");
+}
@@ -0,0 +1,215 @@
+//! Lossless allocation of `FinMoney` values by ratios.
+
+use crate::{FinMoney, FinMoneyError, FinMoneyRoundingStrategy};
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+/// How the minor units left over after rounding every share down are distributed.
+///
+/// Shares with a zero ratio never receive remainder units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FinMoneyRemainderStrategy {
+    /// The whole remainder goes to the first share.
+    First,
+    /// The whole remainder goes to the last share.
+    Last,
+    /// One minor unit per share, to the shares with the largest discarded fractions
+    /// (largest-remainder method). Ties go to the earlier share.
+    #[default]
+    LargestFraction,
+    /// One minor unit per share, in order, starting with the first share.
+    RoundRobin,
+}
+
+impl FinMoney {
+    /// Splits this amount by `ratios` using [`FinMoneyRemainderStrategy::LargestFraction`].
+    ///
+    /// See [`allocate_with`](Self::allocate_with).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`allocate_with`](Self::allocate_with).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinMoney, FinMoneyCurrency};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let bill = FinMoney::new(dec!(100.00), FinMoneyCurrency::USD);
+    /// let shares = bill.allocate(&[dec!(1), dec!(1), dec!(1)])?;
+    ///
+    /// let amounts: Vec<_> = shares.iter().map(|s| s.get_amount()).collect();
+    /// assert_eq!(amounts, vec![dec!(33.34), dec!(33.33), dec!(33.33)]);
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn allocate(&self, ratios: &[Decimal]) -> Result<Vec<FinMoney>, FinMoneyError> {
+        self.allocate_with(ratios, FinMoneyRemainderStrategy::default())
+    }
+
+    /// Splits this amount into shares proportional to `ratios`, rounded to the currency's
+    /// precision, whose sum is exactly this amount.
+    ///
+    /// Every share is first rounded toward zero; the minor units left over are then
+    /// distributed according to `strategy`. Negative amounts are split like their absolute
+    /// value, with every share negated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidAllocation` if `ratios` is empty, contains a negative
+    /// ratio, or sums to zero.
+    /// Returns `FinMoneyError::InvalidAmount` if the amount has more decimal places than the
+    /// currency's precision.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if an intermediate result is out of range.
+    pub fn allocate_with(
+        &self,
+        ratios: &[Decimal],
+        strategy: FinMoneyRemainderStrategy,
+    ) -> Result<Vec<FinMoney>, FinMoneyError> {
+        if ratios.is_empty() {
+            return Err(FinMoneyError::InvalidAllocation(
+                "no ratios given".to_string(),
+            ));
+        }
+        if ratios.iter().any(|r| r.is_sign_negative() && !r.is_zero()) {
+            return Err(FinMoneyError::InvalidAllocation(
+                "ratios must not be negative".to_string(),
+            ));
+        }
+        let total_ratio = ratios
+            .iter()
+            .try_fold(Decimal::ZERO, |acc, r| acc.checked_add(*r))
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        if total_ratio.is_zero() {
+            return Err(FinMoneyError::InvalidAllocation(
+                "ratios sum to zero".to_string(),
+            ));
+        }
+
+        let precision = u32::from(self.get_precision());
+        let amount = self.get_amount();
+        if amount.normalize().scale() > precision {
+            return Err(FinMoneyError::InvalidAmount(format!(
+                "{} has more than {} decimal places",
+                amount, precision
+            )));
+        }
+
+        let total = amount.abs();
+        let unit = Decimal::new(1, precision);
+        let mut shares = Vec::with_capacity(ratios.len());
+        let mut fractions = Vec::with_capacity(ratios.len());
+        for ratio in ratios {
+            let exact = total
+                .checked_mul(*ratio)
+                .and_then(|v| v.checked_div(total_ratio))
+                .ok_or(FinMoneyError::ArithmeticOverflow)?;
+            let floor = exact.round_dp_with_strategy(
+                precision,
+                FinMoneyRoundingStrategy::ToZero.to_decimal_strategy(),
+            );
+            fractions.push(exact - floor);
+            shares.push(floor);
+        }
+
+        let eligible: Vec<usize> = (0..ratios.len())
+            .filter(|&i| !ratios[i].is_zero())
+            .collect();
+        // Exact shares leave less than one minor unit per eligible share. Near the limits of
+        // `Decimal` the quotients lose precision, so any other remainder is reported like an
+        // out-of-range intermediate result.
+        let remainder = shares
+            .iter()
+            .try_fold(Decimal::ZERO, |acc, s| acc.checked_add(*s))
+            .and_then(|allocated| total.checked_sub(allocated))
+            .filter(|r| *r >= Decimal::ZERO)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        let units = remainder
+            .checked_div(unit)
+            .and_then(|units| units.to_usize())
+            .filter(|&units| units <= eligible.len())
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+
+        match strategy {
+            // Shares and remainder are non-negative and sum to `total`, so these cannot overflow.
+            FinMoneyRemainderStrategy::First => shares[eligible[0]] += remainder,
+            FinMoneyRemainderStrategy::Last => shares[eligible[eligible.len() - 1]] += remainder,
+            FinMoneyRemainderStrategy::RoundRobin | FinMoneyRemainderStrategy::LargestFraction => {
+                let mut order = eligible;
+                if strategy == FinMoneyRemainderStrategy::LargestFraction {
+                    // Stable sort keeps the earlier share first on ties.
+                    order.sort_by(|&a, &b| fractions[b].cmp(&fractions[a]));
+                }
+                for i in order.iter().cycle().take(units) {
+                    shares[*i] += unit;
+                }
+            }
+        }
+
+        let negative = amount.is_sign_negative();
+        Ok(shares
+            .into_iter()
+            .map(|share| FinMoney::new(if negative { -share } else { share }, self.get_currency()))
+            .collect())
+    }
+
+    /// Splits this amount into `n` equal shares using
+    /// [`FinMoneyRemainderStrategy::LargestFraction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidAllocation` if `n` is zero, otherwise the same errors as
+    /// [`allocate_with`](Self::allocate_with).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinMoney, FinMoneyCurrency};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let fee = FinMoney::new(dec!(0.05), FinMoneyCurrency::USD);
+    /// let shares = fee.split_evenly(3)?;
+    ///
+    /// // Equal fractions, so the two leftover cents go to the earliest shares.
+    /// let amounts: Vec<_> = shares.iter().map(|s| s.get_amount()).collect();
+    /// assert_eq!(amounts, vec![dec!(0.02), dec!(0.02), dec!(0.01)]);
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn split_evenly(&self, n: usize) -> Result<Vec<FinMoney>, FinMoneyError> {
+        self.split_evenly_with(n, FinMoneyRemainderStrategy::default())
+    }
+
+    /// Splits this amount into `n` equal shares, distributing the remainder per `strategy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidAllocation` if `n` is zero, otherwise the same errors as
+    /// [`allocate_with`](Self::allocate_with).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinMoney, FinMoneyCurrency, FinMoneyRemainderStrategy};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let fee = FinMoney::new(dec!(0.05), FinMoneyCurrency::USD);
+    /// let shares = fee.split_evenly_with(3, FinMoneyRemainderStrategy::Last)?;
+    ///
+    /// let amounts: Vec<_> = shares.iter().map(|s| s.get_amount()).collect();
+    /// assert_eq!(amounts, vec![dec!(0.01), dec!(0.01), dec!(0.03)]);
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn split_evenly_with(
+        &self,
+        n: usize,
+        strategy: FinMoneyRemainderStrategy,
+    ) -> Result<Vec<FinMoney>, FinMoneyError> {
+        if n == 0 {
+            return Err(FinMoneyError::InvalidAllocation(
+                "cannot split into zero shares".to_string(),
+            ));
+        }
+        self.allocate_with(&vec![Decimal::ONE; n], strategy)
+    }
+}
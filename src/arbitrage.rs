@@ -0,0 +1,215 @@
+//! Detection of profitable conversion cycles (arbitrage) over a set of rates.
+
+use crate::{FinExchangeRate, FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+/// A profitable sequence of conversions that starts and ends in the same currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FinArbitrageCycle {
+    hops: Vec<FinExchangeRate>,
+    start: FinMoney,
+    end: FinMoney,
+}
+
+impl FinArbitrageCycle {
+    /// Returns the rates applied, in conversion order.
+    pub fn get_hops(&self) -> &[FinExchangeRate] {
+        &self.hops
+    }
+
+    /// Returns the currencies visited, starting and ending with the start currency.
+    pub fn get_path(&self) -> Vec<FinMoneyCurrency> {
+        let mut path = vec![self.start.get_currency()];
+        path.extend(self.hops.iter().map(|hop| hop.get_quote()));
+        path
+    }
+
+    /// Returns the amount the cycle starts with.
+    pub fn get_start(&self) -> FinMoney {
+        self.start
+    }
+
+    /// Returns the amount the cycle ends with, after fees and rounding.
+    pub fn get_end(&self) -> FinMoney {
+        self.end
+    }
+
+    /// Returns the profit, `end - start`.
+    pub fn get_gain(&self) -> FinMoney {
+        FinMoney::new(
+            self.end.get_amount() - self.start.get_amount(),
+            self.start.get_currency(),
+        )
+    }
+}
+
+/// Finds conversion cycles that turn an amount into more of the same currency.
+///
+/// Rates are directed: a `EUR/USD` rate converts EUR into USD only. Add the opposite direction
+/// explicitly (typically at a different price) if it can be traded.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinArbitrageDetector, FinExchangeRate, FinMoney, iso4217};
+/// use rust_decimal_macros::dec;
+///
+/// let mut detector = FinArbitrageDetector::new();
+/// detector.insert(FinExchangeRate::new(iso4217::USD, iso4217::EUR, dec!(0.92))?);
+/// detector.insert(FinExchangeRate::new(iso4217::EUR, iso4217::GBP, dec!(0.86))?);
+/// detector.insert(FinExchangeRate::new(iso4217::GBP, iso4217::USD, dec!(1.28))?);
+///
+/// let start = FinMoney::new(dec!(10000), iso4217::USD);
+/// let cycles = detector.find_cycles(&start, 3)?;
+///
+/// // 10000 USD -> 9200 EUR -> 7912 GBP -> 10127.36 USD
+/// assert_eq!(cycles[0].get_path(), vec![iso4217::USD, iso4217::EUR, iso4217::GBP, iso4217::USD]);
+/// assert_eq!(cycles[0].get_gain(), FinMoney::new(dec!(127.36), iso4217::USD));
+/// # Ok::<(), finmoney::FinMoneyError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FinArbitrageDetector {
+    // Outgoing rates and per-hop fees per base currency id, keyed by quote currency id.
+    edges: BTreeMap<i32, BTreeMap<i32, (FinExchangeRate, Decimal)>>,
+}
+
+impl FinArbitrageDetector {
+    /// Creates a detector without rates.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a fee-free rate, replacing any rate for the same base and quote.
+    ///
+    /// A rate between a currency and itself is ignored, since converting a currency into
+    /// itself is not a trade.
+    pub fn insert(&mut self, rate: FinExchangeRate) {
+        self.insert_edge(rate, Decimal::ZERO);
+    }
+
+    /// Adds a rate whose conversions lose `fee` (a fraction, e.g. `0.001` for 10 bps) of the
+    /// converted amount, replacing any rate for the same base and quote.
+    ///
+    /// Like [`insert`](Self::insert), a rate between a currency and itself is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidAmount` if `fee` is not in `[0, 1)`.
+    pub fn insert_with_fee(
+        &mut self,
+        rate: FinExchangeRate,
+        fee: Decimal,
+    ) -> Result<(), FinMoneyError> {
+        if fee < Decimal::ZERO || fee >= Decimal::ONE {
+            return Err(FinMoneyError::InvalidAmount(format!(
+                "fee {} must be at least 0 and below 1",
+                fee
+            )));
+        }
+        self.insert_edge(rate, fee);
+        Ok(())
+    }
+
+    fn insert_edge(&mut self, rate: FinExchangeRate, fee: Decimal) {
+        if rate.get_base().is_same_currency(&rate.get_quote()) {
+            return;
+        }
+        self.edges
+            .entry(rate.get_base().get_id())
+            .or_default()
+            .insert(rate.get_quote().get_id(), (rate, fee));
+    }
+
+    /// Returns every cycle of at most `max_hops` conversions that starts and ends in the
+    /// currency of `start` and ends with more than `start`, most profitable first.
+    ///
+    /// Each hop converts at the full rate, deducts its fee, and rounds toward zero to the
+    /// target currency's precision, so reported gains are never overstated. Cycles never visit
+    /// a currency twice. The search enumerates paths, so its cost grows exponentially with
+    /// `max_hops`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidAmount` if `start` is not positive.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if a conversion overflows.
+    pub fn find_cycles(
+        &self,
+        start: &FinMoney,
+        max_hops: usize,
+    ) -> Result<Vec<FinArbitrageCycle>, FinMoneyError> {
+        if !start.is_positive() {
+            return Err(FinMoneyError::InvalidAmount(format!(
+                "starting amount {} must be positive",
+                start
+            )));
+        }
+        let mut cycles = Vec::new();
+        let mut hops = Vec::new();
+        self.search(start, *start, max_hops, &mut hops, &mut cycles)?;
+        cycles.sort_by(|a, b| {
+            b.end
+                .get_amount()
+                .cmp(&a.end.get_amount())
+                .then(a.hops.len().cmp(&b.hops.len()))
+        });
+        Ok(cycles)
+    }
+
+    fn search(
+        &self,
+        start: &FinMoney,
+        current: FinMoney,
+        remaining: usize,
+        hops: &mut Vec<FinExchangeRate>,
+        cycles: &mut Vec<FinArbitrageCycle>,
+    ) -> Result<(), FinMoneyError> {
+        if remaining == 0 {
+            return Ok(());
+        }
+        let Some(edges) = self.edges.get(&current.get_currency().get_id()) else {
+            return Ok(());
+        };
+        for (quote_id, (rate, fee)) in edges {
+            let closes = *quote_id == start.get_currency().get_id();
+            let visited = *quote_id == current.get_currency().get_id()
+                || hops.iter().any(|hop| hop.get_base().get_id() == *quote_id);
+            if visited && !closes {
+                continue;
+            }
+
+            let next = convert(current, rate, *fee)?;
+            hops.push(*rate);
+            if closes {
+                if next.get_amount() > start.get_amount() {
+                    cycles.push(FinArbitrageCycle {
+                        hops: hops.clone(),
+                        start: *start,
+                        end: FinMoney::new(next.get_amount(), start.get_currency()),
+                    });
+                }
+            } else {
+                self.search(start, next, remaining - 1, hops, cycles)?;
+            }
+            hops.pop();
+        }
+        Ok(())
+    }
+}
+
+fn convert(
+    money: FinMoney,
+    rate: &FinExchangeRate,
+    fee: Decimal,
+) -> Result<FinMoney, FinMoneyError> {
+    let amount = money
+        .get_amount()
+        .checked_mul(rate.get_rate())
+        .and_then(|gross| gross.checked_mul(Decimal::ONE - fee))
+        .ok_or(FinMoneyError::ArithmeticOverflow)?;
+    let quote = rate.get_quote();
+    Ok(FinMoney::new(amount, quote).round_dp_with_strategy(
+        u32::from(quote.get_precision()),
+        FinMoneyRoundingStrategy::ToZero,
+    ))
+}
@@ -0,0 +1,269 @@
+//! Multi-currency collections of money.
+
+use crate::{
+    FinExchangeRate, FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy,
+    RateProvider,
+};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+/// Accumulates `FinMoney` values in any number of currencies.
+///
+/// Amounts are kept per currency id, so adding USD to EUR never fails with
+/// `FinMoneyError::CurrencyMismatch`. Currencies whose total reaches zero are dropped, and
+/// iteration is always in ascending currency id order.
+///
+/// A bag serializes as the list of its totals. Deserialization rebuilds the bag with
+/// [`add`](Self::add), dropping zero totals and rejecting a currency that appears twice.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinMoney, FinMoneyBag, FinMoneyCurrency, FinMoneyRoundingStrategy};
+/// use rust_decimal_macros::dec;
+///
+/// let mut bag = FinMoneyBag::new();
+/// bag.add(FinMoney::new(dec!(10.00), FinMoneyCurrency::USD))?;
+/// bag.add(FinMoney::new(dec!(5.00), FinMoneyCurrency::EUR))?;
+/// bag.add(FinMoney::new(dec!(2.50), FinMoneyCurrency::USD))?;
+///
+/// assert_eq!(bag.get(FinMoneyCurrency::USD).get_amount(), dec!(12.50));
+///
+/// let total = bag.collapse(
+///     FinMoneyCurrency::USD,
+///     |from, _to| (from.get_code() == "EUR").then_some(dec!(1.1)),
+///     FinMoneyRoundingStrategy::default(),
+/// )?;
+/// assert_eq!(total.get_amount(), dec!(18.00));
+/// # Ok::<(), finmoney::FinMoneyError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "RawMoneyBag", try_from = "RawMoneyBag")
+)]
+pub struct FinMoneyBag {
+    amounts: BTreeMap<i32, FinMoney>,
+}
+
+impl FinMoneyBag {
+    /// Creates an empty bag.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `money` to the total in its currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the total overflows; the bag is unchanged.
+    pub fn add(&mut self, money: FinMoney) -> Result<(), FinMoneyError> {
+        let id = money.get_currency().get_id();
+        let total = match self.amounts.get(&id) {
+            Some(existing) => existing.checked_add(money)?,
+            None => money,
+        };
+        if total.is_zero() {
+            self.amounts.remove(&id);
+        } else {
+            self.amounts.insert(id, total);
+        }
+        Ok(())
+    }
+
+    /// Subtracts `money` from the total in its currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the total overflows; the bag is unchanged.
+    pub fn subtract(&mut self, money: FinMoney) -> Result<(), FinMoneyError> {
+        self.add(-money)
+    }
+
+    /// Adds every amount of `other` to this bag.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if any total overflows; the bag is unchanged.
+    pub fn merge(&mut self, other: &FinMoneyBag) -> Result<(), FinMoneyError> {
+        let mut merged = self.clone();
+        for money in other.iter() {
+            merged.add(*money)?;
+        }
+        *self = merged;
+        Ok(())
+    }
+
+    /// Returns a bag with every amount negated.
+    pub fn negated(&self) -> FinMoneyBag {
+        FinMoneyBag {
+            amounts: self
+                .amounts
+                .iter()
+                .map(|(id, money)| (*id, money.negated()))
+                .collect(),
+        }
+    }
+
+    /// Returns the total in `currency`, or zero if the bag holds none.
+    pub fn get(&self, currency: FinMoneyCurrency) -> FinMoney {
+        self.amounts
+            .get(&currency.get_id())
+            .copied()
+            .unwrap_or_else(|| FinMoney::zero(currency))
+    }
+
+    /// Returns `true` if the bag holds a non-zero amount in `currency`.
+    pub fn contains(&self, currency: FinMoneyCurrency) -> bool {
+        self.amounts.contains_key(&currency.get_id())
+    }
+
+    /// Returns the number of currencies with a non-zero total.
+    pub fn len(&self) -> usize {
+        self.amounts.len()
+    }
+
+    /// Returns `true` if the bag holds no amounts.
+    pub fn is_empty(&self) -> bool {
+        self.amounts.is_empty()
+    }
+
+    /// Iterates over the totals in ascending currency id order.
+    pub fn iter(&self) -> impl Iterator<Item = &FinMoney> {
+        self.amounts.values()
+    }
+
+    /// Converts every amount into `target` and returns their sum.
+    ///
+    /// `rate(from, to)` returns how many units of `to` one unit of `from` buys, or `None` if no
+    /// rate is known. Amounts already in `target` are not converted. The sum is computed at
+    /// full precision and rounded once to the target currency's precision with `strategy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::MissingExchangeRate` if `rate` returns `None`.
+    /// Returns `FinMoneyError::InvalidExchangeRate` if `rate` returns zero or a negative rate.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if a conversion or the sum overflows.
+    pub fn collapse<F>(
+        &self,
+        target: FinMoneyCurrency,
+        mut rate: F,
+        strategy: FinMoneyRoundingStrategy,
+    ) -> Result<FinMoney, FinMoneyError>
+    where
+        F: FnMut(&FinMoneyCurrency, &FinMoneyCurrency) -> Option<Decimal>,
+    {
+        self.collapse_by(target, strategy, |from| {
+            let rate = rate(&from, &target).ok_or_else(|| FinMoneyError::MissingExchangeRate {
+                base: from.get_code().to_string(),
+                quote: target.get_code().to_string(),
+            })?;
+            FinExchangeRate::new(from, target, rate)
+        })
+    }
+
+    /// Converts every amount into `target` using rates from `provider` and returns their sum.
+    ///
+    /// Like [`collapse`](Self::collapse), the sum is rounded once to the target currency's
+    /// precision with `strategy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of the provider, e.g. `FinMoneyError::MissingExchangeRate`.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if a conversion or the sum overflows.
+    pub fn collapse_with<P>(
+        &self,
+        target: FinMoneyCurrency,
+        provider: &P,
+        strategy: FinMoneyRoundingStrategy,
+    ) -> Result<FinMoney, FinMoneyError>
+    where
+        P: RateProvider + ?Sized,
+    {
+        self.collapse_by(target, strategy, |from| {
+            Ok(provider.get_rate(from, target)?.get_rate())
+        })
+    }
+
+    fn collapse_by<F>(
+        &self,
+        target: FinMoneyCurrency,
+        strategy: FinMoneyRoundingStrategy,
+        mut rate: F,
+    ) -> Result<FinMoney, FinMoneyError>
+    where
+        F: FnMut(FinMoneyCurrency) -> Result<FinExchangeRate, FinMoneyError>,
+    {
+        let mut sum = Decimal::ZERO;
+        for money in self.iter() {
+            let currency = money.get_currency();
+            let converted = if currency.is_same_currency(&target) {
+                money.get_amount()
+            } else {
+                let rate = rate(currency)?;
+                money
+                    .get_amount()
+                    .checked_mul(rate.get_rate())
+                    .ok_or(FinMoneyError::ArithmeticOverflow)?
+            };
+            sum = sum
+                .checked_add(converted)
+                .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        }
+        Ok(FinMoney::new(sum, target)
+            .round_dp_with_strategy(u32::from(target.get_precision()), strategy))
+    }
+}
+
+impl<'a> IntoIterator for &'a FinMoneyBag {
+    type Item = &'a FinMoney;
+    type IntoIter = std::collections::btree_map::Values<'a, i32, FinMoney>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.amounts.values()
+    }
+}
+
+impl From<FinMoney> for FinMoneyBag {
+    fn from(money: FinMoney) -> Self {
+        let mut bag = FinMoneyBag::new();
+        if !money.is_zero() {
+            bag.amounts.insert(money.get_currency().get_id(), money);
+        }
+        bag
+    }
+}
+
+/// The serialized form of [`FinMoneyBag`]: its totals in ascending currency id order.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+struct RawMoneyBag(Vec<FinMoney>);
+
+#[cfg(feature = "serde")]
+impl From<FinMoneyBag> for RawMoneyBag {
+    fn from(bag: FinMoneyBag) -> Self {
+        RawMoneyBag(bag.amounts.into_values().collect())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawMoneyBag> for FinMoneyBag {
+    type Error = FinMoneyError;
+
+    fn try_from(raw: RawMoneyBag) -> Result<Self, Self::Error> {
+        let mut bag = FinMoneyBag::new();
+        let mut seen = std::collections::BTreeSet::new();
+        for money in raw.0 {
+            if !seen.insert(money.get_currency().get_id()) {
+                return Err(FinMoneyError::InvalidAmount(format!(
+                    "more than one total in {}",
+                    money.get_currency_code()
+                )));
+            }
+            bag.add(money)?;
+        }
+        Ok(bag)
+    }
+}
@@ -0,0 +1,190 @@
+//! Fixed-rate conversion of legacy currencies replaced by a new currency (e.g. the euro
+//! changeover).
+//!
+//! The rules follow the euro changeover regulation (EC No 1103/97):
+//!
+//! - each legacy currency has one fixed rate, expressed as one unit of the new currency in the
+//!   legacy currency, with exactly six significant figures;
+//! - rates are never inverted: legacy amounts are converted into the new currency by dividing;
+//! - conversions between two legacy currencies always go through the new currency, whose
+//!   intermediate amount is rounded to no fewer than three decimal places;
+//! - amounts are rounded half up (away from zero), which is not configurable.
+
+use crate::{FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+/// Fixed conversion rates between a new currency and the legacy currencies it replaces.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinFixedRateTable, FinMoney, FinMoneyCurrency, iso4217};
+/// use rust_decimal_macros::dec;
+///
+/// let dem = FinMoneyCurrency::new(276, "DEM", None, 2)?;
+/// let frf = FinMoneyCurrency::new(250, "FRF", None, 2)?;
+///
+/// let mut table = FinFixedRateTable::new(iso4217::EUR);
+/// table.insert(dem, dec!(1.95583))?;
+/// table.insert(frf, dec!(6.55957))?;
+///
+/// // 100 DEM -> 51.129 EUR (three decimals) -> 335.38 FRF
+/// let francs = table.convert(&FinMoney::new(dec!(100), dem), frf)?;
+/// assert_eq!(francs, FinMoney::new(dec!(335.38), frf));
+/// # Ok::<(), finmoney::FinMoneyError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct FinFixedRateTable {
+    anchor: FinMoneyCurrency,
+    rates: HashMap<i32, (FinMoneyCurrency, Decimal)>,
+    intermediate_precision: u32,
+}
+
+impl FinFixedRateTable {
+    /// Number of significant figures every fixed rate must have.
+    pub const SIGNIFICANT_FIGURES: u32 = 6;
+
+    /// The rounding the regulation mandates for intermediate and final amounts.
+    pub const ROUNDING: FinMoneyRoundingStrategy = FinMoneyRoundingStrategy::MidpointAwayFromZero;
+
+    /// Creates an empty table for legacy currencies replaced by `anchor`.
+    ///
+    /// Intermediate amounts are rounded to three decimal places, and results to the target
+    /// precision, both with [`ROUNDING`](Self::ROUNDING).
+    pub fn new(anchor: FinMoneyCurrency) -> Self {
+        Self {
+            anchor,
+            rates: HashMap::new(),
+            intermediate_precision: 3,
+        }
+    }
+
+    /// Sets the decimal places intermediate amounts in the new currency are rounded to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidPrecision` if `dp` is below 3 or above 28.
+    pub fn with_intermediate_precision(mut self, dp: u32) -> Result<Self, FinMoneyError> {
+        if !(3..=28).contains(&dp) {
+            return Err(FinMoneyError::InvalidPrecision(dp));
+        }
+        self.intermediate_precision = dp;
+        Ok(self)
+    }
+
+    /// Returns the currency replacing the legacy currencies.
+    pub fn get_anchor(&self) -> FinMoneyCurrency {
+        self.anchor
+    }
+
+    /// Returns the fixed rate of `legacy`: one unit of the new currency in `legacy`.
+    pub fn get_rate(&self, legacy: FinMoneyCurrency) -> Option<Decimal> {
+        self.rates.get(&legacy.get_id()).map(|(_, rate)| *rate)
+    }
+
+    /// Fixes the rate of `legacy` as `rate` units per unit of the new currency.
+    ///
+    /// Trailing zeros count: GRD's `340.750` must be written with its final zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidExchangeRate` if `rate` is not positive or does not have
+    /// exactly six significant figures.
+    /// Returns `FinMoneyError::InvalidCurrencyPair` if `legacy` is the new currency.
+    /// Returns `FinMoneyError::CurrencyConflict` if `legacy` already has a different rate.
+    pub fn insert(&mut self, legacy: FinMoneyCurrency, rate: Decimal) -> Result<(), FinMoneyError> {
+        if rate <= Decimal::ZERO || significant_figures(rate) != Self::SIGNIFICANT_FIGURES {
+            return Err(FinMoneyError::InvalidExchangeRate(rate));
+        }
+        if legacy.is_same_currency(&self.anchor) {
+            return Err(FinMoneyError::InvalidCurrencyPair(format!(
+                "{}/{}",
+                self.anchor.get_code(),
+                legacy.get_code()
+            )));
+        }
+        if let Some((existing, fixed)) = self.rates.get(&legacy.get_id()) {
+            if *fixed == rate && existing.get_code() == legacy.get_code() {
+                return Ok(());
+            }
+            return Err(FinMoneyError::CurrencyConflict {
+                existing: self.describe(*existing, *fixed),
+                rejected: self.describe(legacy, rate),
+            });
+        }
+        self.rates.insert(legacy.get_id(), (legacy, rate));
+        Ok(())
+    }
+
+    /// Converts `money` into `target` following the fixed-rate rules.
+    ///
+    /// The result is rounded to the target currency's precision. Converting into the currency
+    /// `money` is already in returns it unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::MissingExchangeRate` if a legacy currency involved has no fixed
+    /// rate, or neither currency is the new currency or a legacy one.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn convert(
+        &self,
+        money: &FinMoney,
+        target: FinMoneyCurrency,
+    ) -> Result<FinMoney, FinMoneyError> {
+        let source = money.get_currency();
+        if source.is_same_currency(&target) {
+            return Ok(*money);
+        }
+        let missing = || FinMoneyError::MissingExchangeRate {
+            base: source.get_code().to_string(),
+            quote: target.get_code().to_string(),
+        };
+
+        let anchored = if source.is_same_currency(&self.anchor) {
+            money.get_amount()
+        } else {
+            let rate = self.get_rate(source).ok_or_else(missing)?;
+            let amount = money
+                .get_amount()
+                .checked_div(rate)
+                .ok_or(FinMoneyError::ArithmeticOverflow)?;
+            if target.is_same_currency(&self.anchor) {
+                return Ok(self.round(amount, target));
+            }
+            amount.round_dp_with_strategy(
+                self.intermediate_precision,
+                Self::ROUNDING.to_decimal_strategy(),
+            )
+        };
+
+        let rate = self.get_rate(target).ok_or_else(missing)?;
+        let amount = anchored
+            .checked_mul(rate)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        Ok(self.round(amount, target))
+    }
+
+    fn round(&self, amount: Decimal, currency: FinMoneyCurrency) -> FinMoney {
+        FinMoney::new(amount, currency)
+            .round_dp_with_strategy(u32::from(currency.get_precision()), Self::ROUNDING)
+    }
+
+    fn describe(&self, legacy: FinMoneyCurrency, rate: Decimal) -> String {
+        format!(
+            "1 {} = {} {}",
+            self.anchor.get_code(),
+            rate,
+            legacy.get_code()
+        )
+    }
+}
+
+fn significant_figures(value: Decimal) -> u32 {
+    let mantissa = value.mantissa().unsigned_abs();
+    if mantissa == 0 {
+        0
+    } else {
+        mantissa.ilog10() + 1
+    }
+}
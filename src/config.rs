@@ -0,0 +1,285 @@
+//! Loading currency definitions from TOML, JSON or CSV configuration (feature `config`).
+//!
+//! Every format describes the same four fields per currency: `id`, `code`, optional `name`
+//! and `precision`.
+//!
+//! ```toml
+//! [[currency]]
+//! id = 1
+//! code = "USD"
+//! name = "US Dollar"
+//! precision = 2
+//! ```
+//!
+//! ```json
+//! [{ "id": 1, "code": "USD", "name": "US Dollar", "precision": 2 }]
+//! ```
+//!
+//! ```text
+//! id,code,name,precision
+//! 1,USD,US Dollar,2
+//! ```
+//!
+//! Unlike [`FinMoneyCurrency::new`], codes and names are never sanitized: anything that is
+//! not valid ASCII of the allowed length is reported as an error for its line.
+
+use crate::error::FinMoneyError;
+use crate::{FinMoneyCurrency, FinMoneyCurrencyRegistry};
+use serde::Deserialize;
+use std::fmt;
+use std::path::Path;
+use tinystr::TinyAsciiStr;
+
+/// Supported configuration formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FinMoneyConfigFormat {
+    /// A TOML document with a `[[currency]]` array of tables.
+    Toml,
+    /// A JSON array of currency objects.
+    Json,
+    /// CSV with an `id,code,name,precision` header row; an empty name means no name.
+    Csv,
+}
+
+impl FinMoneyConfigFormat {
+    /// Detects the format from a file extension (`toml`, `json` or `csv`, case-insensitive).
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let ext = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "toml" => Some(Self::Toml),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// An error for a single entry of a currency configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinMoneyConfigError {
+    /// The 1-based line on which the offending entry starts, if known.
+    pub line: Option<usize>,
+    /// The underlying error, e.g. `FinMoneyError::InvalidPrecision`.
+    pub error: FinMoneyError,
+}
+
+impl fmt::Display for FinMoneyConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.error),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for FinMoneyConfigError {}
+
+/// Result type for configuration loading; all errors found are reported together.
+pub type ConfigResult<T> = std::result::Result<T, Vec<FinMoneyConfigError>>;
+
+#[derive(Deserialize)]
+struct RawCurrency {
+    id: i32,
+    code: String,
+    name: Option<String>,
+    precision: u32,
+}
+
+impl RawCurrency {
+    fn into_currency(self) -> Result<FinMoneyCurrency, FinMoneyError> {
+        if self.precision > 28 {
+            return Err(FinMoneyError::InvalidPrecision(self.precision));
+        }
+        let code = match TinyAsciiStr::<16>::try_from_str(&self.code) {
+            Ok(code) if !self.code.trim().is_empty() => code,
+            _ => return Err(FinMoneyError::InvalidCurrencyCode(self.code)),
+        };
+        let name = match self.name {
+            Some(name) => match TinyAsciiStr::<52>::try_from_str(&name) {
+                Ok(parsed) => Some(parsed),
+                Err(_) => return Err(FinMoneyError::InvalidCurrencyName(name)),
+            },
+            None => None,
+        };
+        FinMoneyCurrency::new_from_tiny(self.id, code, name, self.precision as u8)
+    }
+}
+
+#[derive(Deserialize)]
+struct TomlDocument {
+    #[serde(default)]
+    currency: Vec<toml::Spanned<toml::Table>>,
+}
+
+/// Parses currency definitions from a configuration string.
+///
+/// All entries are validated; if any of them is invalid, every error is returned with the
+/// line on which its entry starts.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::config::{FinMoneyConfigFormat, parse_currencies};
+/// use finmoney::FinMoneyError;
+///
+/// let csv = "id,code,name,precision\n1,USD,US Dollar,2\n2,BAD,,40\n";
+/// let errors = parse_currencies(csv, FinMoneyConfigFormat::Csv).unwrap_err();
+/// assert_eq!(errors[0].line, Some(3));
+/// assert_eq!(errors[0].error, FinMoneyError::InvalidPrecision(40));
+/// ```
+pub fn parse_currencies(
+    input: &str,
+    format: FinMoneyConfigFormat,
+) -> ConfigResult<Vec<FinMoneyCurrency>> {
+    parse_entries(input, format).map(|entries| entries.into_iter().map(|(_, c)| c).collect())
+}
+
+impl FinMoneyCurrencyRegistry {
+    /// Parses currency definitions and registers them atomically.
+    ///
+    /// Returns the number of newly registered currencies. If any entry is invalid or
+    /// conflicts with the registry (or another entry), nothing is registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns every per-entry error, each tagged with its line number.
+    pub fn load_str(&self, input: &str, format: FinMoneyConfigFormat) -> ConfigResult<usize> {
+        let batch = parse_entries(input, format)?;
+        self.register_batch(batch).map_err(|conflicts| {
+            conflicts
+                .into_iter()
+                .map(|(line, error)| FinMoneyConfigError {
+                    line: Some(line),
+                    error,
+                })
+                .collect()
+        })
+    }
+
+    /// Reads a configuration file and registers its currencies atomically.
+    ///
+    /// The format is detected from the file extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidConfig` if the file cannot be read or has an unknown
+    /// extension, otherwise the same errors as [`load_str`](Self::load_str).
+    pub fn load_file(&self, path: impl AsRef<Path>) -> ConfigResult<usize> {
+        let path = path.as_ref();
+        let format = FinMoneyConfigFormat::from_path(path).ok_or_else(|| {
+            config_error(None, format!("unknown file extension: {}", path.display()))
+        })?;
+        let input = std::fs::read_to_string(path)
+            .map_err(|e| config_error(None, format!("{}: {}", path.display(), e)))?;
+        self.load_str(&input, format)
+    }
+}
+
+type RawEntry = (usize, Result<RawCurrency, FinMoneyError>);
+
+fn parse_entries(
+    input: &str,
+    format: FinMoneyConfigFormat,
+) -> ConfigResult<Vec<(usize, FinMoneyCurrency)>> {
+    let entries = match format {
+        FinMoneyConfigFormat::Toml => parse_toml(input)?,
+        FinMoneyConfigFormat::Json => parse_json(input)?,
+        FinMoneyConfigFormat::Csv => parse_csv(input)?,
+    };
+
+    let mut currencies = Vec::with_capacity(entries.len());
+    let mut errors = Vec::new();
+    for (line, raw) in entries {
+        match raw.and_then(RawCurrency::into_currency) {
+            Ok(currency) => currencies.push((line, currency)),
+            Err(error) => errors.push(FinMoneyConfigError {
+                line: Some(line),
+                error,
+            }),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(currencies)
+    } else {
+        Err(errors)
+    }
+}
+
+fn parse_toml(input: &str) -> ConfigResult<Vec<RawEntry>> {
+    let document: TomlDocument = toml::from_str(input).map_err(|e| {
+        let line = e.span().map(|span| line_at(input, span.start));
+        config_error(line, e.message().to_string())
+    })?;
+
+    Ok(document
+        .currency
+        .into_iter()
+        .map(|entry| {
+            let line = line_at(input, entry.span().start);
+            let raw = toml::Value::Table(entry.into_inner())
+                .try_into::<RawCurrency>()
+                .map_err(|e| FinMoneyError::InvalidConfig(e.message().to_string()));
+            (line, raw)
+        })
+        .collect())
+}
+
+fn parse_json(input: &str) -> ConfigResult<Vec<RawEntry>> {
+    let entries: Vec<&serde_json::value::RawValue> =
+        serde_json::from_str(input).map_err(|e| config_error(Some(e.line()), e.to_string()))?;
+
+    Ok(entries
+        .into_iter()
+        .map(|entry| {
+            // `RawValue` borrows from `input`, so the pointer distance is the byte offset.
+            let offset = entry.get().as_ptr() as usize - input.as_ptr() as usize;
+            let line = line_at(input, offset);
+            let raw = serde_json::from_str::<RawCurrency>(entry.get())
+                .map_err(|e| FinMoneyError::InvalidConfig(e.to_string()));
+            (line, raw)
+        })
+        .collect())
+}
+
+fn parse_csv(input: &str) -> ConfigResult<Vec<RawEntry>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(input.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| config_error(Some(1), e.to_string()))?
+        .clone();
+
+    Ok(reader
+        .records()
+        .map(|record| match record {
+            Ok(record) => {
+                let line = record.position().map_or(0, |p| p.line() as usize);
+                let raw = record
+                    .deserialize::<RawCurrency>(Some(&headers))
+                    .map_err(|e| FinMoneyError::InvalidConfig(e.to_string()));
+                (line, raw)
+            }
+            Err(e) => {
+                let line = e.position().map_or(0, |p| p.line() as usize);
+                (line, Err(FinMoneyError::InvalidConfig(e.to_string())))
+            }
+        })
+        .collect())
+}
+
+fn line_at(input: &str, offset: usize) -> usize {
+    input.as_bytes()[..offset.min(input.len())]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count()
+        + 1
+}
+
+fn config_error(line: Option<usize>, msg: String) -> Vec<FinMoneyConfigError> {
+    vec![FinMoneyConfigError {
+        line,
+        error: FinMoneyError::InvalidConfig(msg),
+    }]
+}
@@ -8,8 +8,11 @@ use tinystr::TinyAsciiStr;
 /// The currency defines how monetary values should be formatted and rounded.
 /// Each currency has a unique ID, a code (like "USD", "EUR"), an optional human-readable name,
 /// and a precision that determines how many decimal places are significant.
+///
+/// Deserialization applies the same checks as [`new`](Self::new).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCurrency"))]
 pub struct FinMoneyCurrency {
     id: i32,
     name: Option<TinyAsciiStr<52>>,
@@ -40,6 +43,11 @@ impl FinMoneyCurrency {
 
     /// Creates a new currency with the specified parameters.
     ///
+    /// Each non-ASCII character of the code or name is replaced with `_`, so "Złoty" becomes
+    /// "Z_oty". Keep the Unicode text in the registry with
+    /// [`FinCurrencyMetadata::with_display_name`](crate::FinCurrencyMetadata::with_display_name),
+    /// or use [`try_new_strict`](Self::try_new_strict) to reject it.
+    ///
     /// # Arguments
     ///
     /// * `id` - Unique identifier for the currency
@@ -60,6 +68,9 @@ impl FinMoneyCurrency {
     ///
     /// let usd = FinMoneyCurrency::new(1, "USD".to_string(), Some("US Dollar".to_string()), 2)?;
     /// let btc = FinMoneyCurrency::new(2, "BTC".to_string(), Some("Bitcoin".to_string()), 8)?;
+    ///
+    /// let pln = FinMoneyCurrency::new(985, "PLN", Some("Złoty".to_string()), 2)?;
+    /// assert_eq!(pln.get_name(), Some("Z_oty"));
     /// # Ok::<(), finmoney::FinMoneyError>(())
     /// ```
     pub fn new(
@@ -91,6 +102,41 @@ impl FinMoneyCurrency {
         })
     }
 
+    /// Creates a new currency like [`new`](Self::new), but rejects non-ASCII text instead of
+    /// sanitizing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidPrecision` if precision > 28.
+    /// Returns `FinMoneyError::InvalidCurrencyCode` if the code is invalid or not ASCII.
+    /// Returns `FinMoneyError::InvalidCurrencyName` if the name is invalid or not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinMoneyCurrency, FinMoneyError};
+    ///
+    /// assert!(matches!(
+    ///     FinMoneyCurrency::try_new_strict(985, "PLN", Some("Złoty".to_string()), 2),
+    ///     Err(FinMoneyError::InvalidCurrencyName(_))
+    /// ));
+    /// ```
+    pub fn try_new_strict(
+        id: i32,
+        code: impl Into<String>,
+        name: Option<String>,
+        precision: u8,
+    ) -> Result<FinMoneyCurrency> {
+        let code = code.into();
+        if !code.is_ascii() {
+            return Err(FinMoneyError::InvalidCurrencyCode(code));
+        }
+        if let Some(n) = name.as_ref().filter(|n| !n.is_ascii()) {
+            return Err(FinMoneyError::InvalidCurrencyName(n.clone()));
+        }
+        Self::new(id, code, name, precision)
+    }
+
     /// Creates a new currency using pre-calculated `TinyAsciiStr` values.
     ///
     /// This method is more efficient than `new()` when you already have `TinyAsciiStr` values,
@@ -169,8 +215,42 @@ impl FinMoneyCurrency {
         }
     }
 
+    /// Creates a currency in a `const` context.
+    ///
+    /// Backs the built-in currency tables and the `currency!` macro. Invalid input aborts
+    /// compilation when evaluated in a `const` item, so it must never be called with runtime
+    /// data; use `new()` instead.
+    #[doc(hidden)]
+    pub const fn from_static(
+        id: i32,
+        code: &str,
+        name: Option<&str>,
+        precision: u8,
+    ) -> FinMoneyCurrency {
+        assert!(precision <= 28, "currency precision must be <= 28");
+        assert!(!code.is_empty(), "currency code must not be empty");
+        let code = match TinyAsciiStr::try_from_str(code) {
+            Ok(code) => code,
+            Err(_) => panic!("currency code must be ASCII and at most 16 bytes"),
+        };
+        let name = match name {
+            Some(name) => match TinyAsciiStr::try_from_str(name) {
+                Ok(name) => Some(name),
+                Err(_) => panic!("currency name must be ASCII and at most 52 bytes"),
+            },
+            None => None,
+        };
+
+        Self {
+            id,
+            name,
+            code,
+            precision,
+        }
+    }
+
     /// Returns the unique identifier of this currency.
-    pub fn get_id(&self) -> i32 {
+    pub const fn get_id(&self) -> i32 {
         self.id
     }
 
@@ -180,12 +260,12 @@ impl FinMoneyCurrency {
     }
 
     /// Returns the currency code (e.g., "USD", "EUR").
-    pub fn get_code(&self) -> &str {
+    pub const fn get_code(&self) -> &str {
         self.code.as_str()
     }
 
     /// Returns the precision (number of decimal places) for this currency.
-    pub fn get_precision(&self) -> u8 {
+    pub const fn get_precision(&self) -> u8 {
         self.precision
     }
 
@@ -252,37 +332,44 @@ impl FinMoneyCurrency {
     }
 }
 
+/// The serialized form of [`FinMoneyCurrency`], validated by [`FinMoneyCurrency::new`] on
+/// deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawCurrency {
+    id: i32,
+    name: Option<TinyAsciiStr<52>>,
+    code: TinyAsciiStr<16>,
+    precision: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCurrency> for FinMoneyCurrency {
+    type Error = FinMoneyError;
+
+    fn try_from(raw: RawCurrency) -> Result<Self> {
+        FinMoneyCurrency::new(
+            raw.id,
+            raw.code.as_str(),
+            raw.name.map(|name| name.to_string()),
+            raw.precision,
+        )
+    }
+}
+
 // Common currency constants
 impl FinMoneyCurrency {
-    /// US Dollar with 2 decimal places precision.
-    pub const USD: FinMoneyCurrency = FinMoneyCurrency {
-        id: 1,
-        name: None, // TinyAsciiStr doesn't support const construction with Some
-        code: unsafe { TinyAsciiStr::from_utf8_unchecked(*b"USD\0\0\0\0\0\0\0\0\0\0\0\0\0") },
-        precision: 2,
-    };
-
-    /// Euro with 2 decimal places precision.
-    pub const EUR: FinMoneyCurrency = FinMoneyCurrency {
-        id: 2,
-        name: None,
-        code: unsafe { TinyAsciiStr::from_utf8_unchecked(*b"EUR\0\0\0\0\0\0\0\0\0\0\0\0\0") },
-        precision: 2,
-    };
+    /// US Dollar with 2 decimal places precision; the same currency as
+    /// [`iso4217::USD`](crate::iso4217::USD).
+    pub const USD: FinMoneyCurrency = crate::iso4217::USD;
+
+    /// Euro with 2 decimal places precision; the same currency as
+    /// [`iso4217::EUR`](crate::iso4217::EUR).
+    pub const EUR: FinMoneyCurrency = crate::iso4217::EUR;
 
     /// Bitcoin with 8 decimal places precision.
-    pub const BTC: FinMoneyCurrency = FinMoneyCurrency {
-        id: 3,
-        name: None,
-        code: unsafe { TinyAsciiStr::from_utf8_unchecked(*b"BTC\0\0\0\0\0\0\0\0\0\0\0\0\0") },
-        precision: 8,
-    };
+    pub const BTC: FinMoneyCurrency = FinMoneyCurrency::from_static(3, "BTC", None, 8);
 
     /// Ethereum with 18 decimal places precision.
-    pub const ETH: FinMoneyCurrency = FinMoneyCurrency {
-        id: 4,
-        name: None,
-        code: unsafe { TinyAsciiStr::from_utf8_unchecked(*b"ETH\0\0\0\0\0\0\0\0\0\0\0\0\0") },
-        precision: 18,
-    };
+    pub const ETH: FinMoneyCurrency = FinMoneyCurrency::from_static(4, "ETH", None, 18);
 }
@@ -0,0 +1,181 @@
+//! Calendar dates for rate histories and currency metadata.
+
+use crate::FinMoneyError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A day in the proleptic Gregorian calendar, between years 1 and 9999.
+///
+/// Dates order chronologically and format as ISO 8601 (`YYYY-MM-DD`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FinDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl FinDate {
+    /// Creates a new date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidDate` if the year is outside 1..=9999 or the day does not
+    /// exist in the given month.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::FinDate;
+    ///
+    /// let date = FinDate::new(2024, 2, 29)?;
+    /// assert_eq!(date.to_string(), "2024-02-29");
+    /// assert!(FinDate::new(2023, 2, 29).is_err());
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn new(year: u16, month: u8, day: u8) -> Result<FinDate, FinMoneyError> {
+        if !(1..=9999).contains(&year)
+            || !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+        {
+            return Err(FinMoneyError::InvalidDate(format!(
+                "{:04}-{:02}-{:02}",
+                year, month, day
+            )));
+        }
+        Ok(FinDate { year, month, day })
+    }
+
+    /// Returns the date `days` days after 1970-01-01 (negative values go before it).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidDate` if the result is outside years 1..=9999.
+    pub fn from_days_since_epoch(days: i64) -> Result<FinDate, FinMoneyError> {
+        // Howard Hinnant's civil_from_days.
+        let z = days
+            .checked_add(719_468)
+            .ok_or_else(|| FinMoneyError::InvalidDate(format!("day {}", days)))?;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        match u16::try_from(year) {
+            Ok(year) if (1..=9999).contains(&year) => FinDate::new(year, month as u8, day as u8),
+            _ => Err(FinMoneyError::InvalidDate(format!("day {}", days))),
+        }
+    }
+
+    /// Returns the number of days since 1970-01-01 (negative for earlier dates).
+    pub fn to_days_since_epoch(&self) -> i64 {
+        // Howard Hinnant's days_from_civil.
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let mp = (month + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Returns the year.
+    pub fn get_year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month (1-12).
+    pub fn get_month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month (1-31).
+    pub fn get_day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns `true` if this date's year is a leap year.
+    pub fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+
+    /// Returns the number of days from this date to `other` (negative if `other` is earlier).
+    pub fn days_until(&self, other: FinDate) -> i64 {
+        other.to_days_since_epoch() - self.to_days_since_epoch()
+    }
+
+    /// Returns the date `days` days later (or earlier, if negative).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidDate` if the result is outside years 1..=9999.
+    pub fn add_days(&self, days: i64) -> Result<FinDate, FinMoneyError> {
+        let target = self
+            .to_days_since_epoch()
+            .checked_add(days)
+            .ok_or_else(|| FinMoneyError::InvalidDate(format!("{} + {} days", self, days)))?;
+        FinDate::from_days_since_epoch(target)
+    }
+}
+
+fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for FinDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for FinDate {
+    type Err = FinMoneyError;
+
+    /// Parses an ISO 8601 calendar date (`YYYY-MM-DD`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || FinMoneyError::InvalidDate(s.to_string());
+        let bytes = s.as_bytes();
+        if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(invalid());
+        }
+        let field = |range: std::ops::Range<usize>| {
+            let part = &s[range];
+            if part.bytes().all(|b| b.is_ascii_digit()) {
+                part.parse::<u16>().map_err(|_| invalid())
+            } else {
+                Err(invalid())
+            }
+        };
+        let (year, month, day) = (field(0..4)?, field(5..7)?, field(8..10)?);
+        FinDate::new(year, month as u8, day as u8).map_err(|_| invalid())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FinDate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FinDate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
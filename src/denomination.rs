@@ -0,0 +1,301 @@
+//! Named denomination units of a currency (e.g. satoshi, gwei, wei).
+
+use crate::error::{FinMoneyError, Result};
+use crate::{FinMoney, FinMoneyCurrency, FinMoneyCurrencyRegistry};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// A named unit of a currency and its value in whole currency units.
+///
+/// Currencies with code `BTC` come with `sat` (10^-8), `bit` (10^-6) and `mBTC` (10^-3);
+/// currencies with code `ETH` come with `wei` (10^-18) and `gwei` (10^-9). Other units are
+/// attached to a registered currency with
+/// [`FinMoneyCurrencyRegistry::set_unit`](crate::FinMoneyCurrencyRegistry::set_unit). Unit names
+/// are case-sensitive.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinMoney, FinMoneyCurrency};
+/// use rust_decimal_macros::dec;
+///
+/// let fee = FinMoney::from_unit(dec!(21000), "gwei", FinMoneyCurrency::ETH)?;
+/// assert_eq!(fee.get_amount(), dec!(0.000021));
+/// assert_eq!(fee.to_unit("wei")?, dec!(21000000000000));
+/// # Ok::<(), finmoney::FinMoneyError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinDenomination {
+    name: String,
+    factor: Decimal,
+}
+
+impl FinDenomination {
+    /// Creates a unit worth `factor` whole units of its currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidUnit` if the name is empty or only whitespace, or if
+    /// `factor` is not positive.
+    pub fn new(name: impl Into<String>, factor: Decimal) -> Result<Self> {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return Err(FinMoneyError::InvalidUnit("unit name is empty".to_string()));
+        }
+        if factor <= Decimal::ZERO {
+            return Err(FinMoneyError::InvalidUnit(format!(
+                "{} has non-positive factor {}",
+                name, factor
+            )));
+        }
+        Ok(Self {
+            name,
+            factor: factor.normalize(),
+        })
+    }
+
+    /// Returns the built-in unit `name` of `currency`, if there is one.
+    pub fn builtin(currency: &FinMoneyCurrency, name: &str) -> Option<Self> {
+        builtin_units(currency.get_code())
+            .iter()
+            .find(|(builtin, _)| *builtin == name)
+            .map(|(name, factor)| Self {
+                name: name.to_string(),
+                factor: *factor,
+            })
+    }
+
+    /// Returns the built-in units of `currency`, from smallest to largest.
+    pub fn builtins(currency: &FinMoneyCurrency) -> Vec<Self> {
+        builtin_units(currency.get_code())
+            .iter()
+            .map(|(name, factor)| Self {
+                name: name.to_string(),
+                factor: *factor,
+            })
+            .collect()
+    }
+
+    /// Returns the unit name (e.g. `"gwei"`).
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value of one unit in whole currency units (e.g. `0.000000001` for gwei).
+    pub fn get_factor(&self) -> Decimal {
+        self.factor
+    }
+}
+
+/// Built-in units per currency code, from smallest to largest.
+fn builtin_units(code: &str) -> &'static [(&'static str, Decimal)] {
+    const BTC: [(&str, Decimal); 3] = [
+        ("sat", dec!(0.00000001)),
+        ("bit", dec!(0.000001)),
+        ("mBTC", dec!(0.001)),
+    ];
+    const ETH: [(&str, Decimal); 2] = [
+        ("wei", dec!(0.000000000000000001)),
+        ("gwei", dec!(0.000000001)),
+    ];
+
+    match code {
+        "BTC" => &BTC,
+        "ETH" => &ETH,
+        _ => &[],
+    }
+}
+
+/// Returns the 256-bit product of `a` and `b` as `(high, low)` halves.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & MASK);
+    let (b1, b0) = (b >> 64, b & MASK);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    let mid = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let low = (p00 & MASK) | (mid << 64);
+    let high = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (high, low)
+}
+
+/// Multiplies a 256-bit value by `10^exp`, or returns `None` if it overflows.
+fn scale_up(mut value: (u128, u128), exp: u32) -> Option<(u128, u128)> {
+    for _ in 0..exp {
+        let (carry, low) = widening_mul(value.1, 10);
+        let high = value.0.checked_mul(10)?.checked_add(carry)?;
+        value = (high, low);
+    }
+    Some(value)
+}
+
+/// Returns whether `product` is exactly `a * b`.
+///
+/// `Decimal` multiplication and division round to 28 digits instead of failing, so exactness is
+/// decided on the mantissas: `m_a * m_b / 10^(s_a + s_b)` must equal `m_p / 10^s_p`.
+fn is_exact_product(a: Decimal, b: Decimal, product: Decimal) -> bool {
+    if a.is_zero() || b.is_zero() || product.is_zero() {
+        return (a.is_zero() || b.is_zero()) && product.is_zero();
+    }
+    if product.is_sign_negative() != (a.is_sign_negative() != b.is_sign_negative()) {
+        return false;
+    }
+
+    let factors = widening_mul(a.mantissa().unsigned_abs(), b.mantissa().unsigned_abs());
+    let factors_scale = a.scale() + b.scale();
+    let product_mantissa = (0, product.mantissa().unsigned_abs());
+    let (lhs, rhs) = if factors_scale >= product.scale() {
+        (
+            scale_up(product_mantissa, factors_scale - product.scale()),
+            Some(factors),
+        )
+    } else {
+        (
+            Some(product_mantissa),
+            scale_up(factors, product.scale() - factors_scale),
+        )
+    };
+    lhs.is_some() && lhs == rhs
+}
+
+impl FinMoney {
+    /// Creates money from an amount expressed in a built-in unit of `currency` (see
+    /// [`FinDenomination`]).
+    ///
+    /// The conversion is exact.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidUnit` if the currency has no such built-in unit.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the amount does not fit in a `Decimal`.
+    /// Returns `FinMoneyError::InvalidAmount` if the result cannot be represented exactly or is
+    /// not a whole number of the currency's smallest unit (e.g. half a satoshi).
+    pub fn from_unit(amount: Decimal, unit: &str, currency: FinMoneyCurrency) -> Result<FinMoney> {
+        let denomination =
+            require_unit(&currency, unit, FinDenomination::builtin(&currency, unit))?;
+        FinMoney::from_denomination(amount, &denomination, currency)
+    }
+
+    /// Like [`from_unit`](Self::from_unit), also accepting units attached to `currency` in
+    /// `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`from_unit`](Self::from_unit).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinDenomination, FinMoney, FinMoneyCurrency, FinMoneyCurrencyRegistry};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let sol = FinMoneyCurrency::new(501, "SOL", Some("Solana".to_string()), 9)?;
+    /// let registry = FinMoneyCurrencyRegistry::with_currencies([sol])?;
+    /// registry.set_unit(sol, FinDenomination::new("lamport", dec!(0.000000001))?)?;
+    ///
+    /// let rent = FinMoney::from_unit_with(dec!(890880), "lamport", sol, &registry)?;
+    /// assert_eq!(rent.get_amount(), dec!(0.00089088));
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn from_unit_with(
+        amount: Decimal,
+        unit: &str,
+        currency: FinMoneyCurrency,
+        registry: &FinMoneyCurrencyRegistry,
+    ) -> Result<FinMoney> {
+        let denomination = require_unit(&currency, unit, registry.get_unit(&currency, unit))?;
+        FinMoney::from_denomination(amount, &denomination, currency)
+    }
+
+    /// Returns the amount expressed in a built-in unit of its currency (see
+    /// [`FinDenomination`]).
+    ///
+    /// The conversion is exact.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidUnit` if the currency has no such built-in unit.
+    /// Returns `FinMoneyError::InvalidAmount` if the amount is not a whole number of the
+    /// currency's smallest unit, or cannot be represented exactly in `unit`.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result does not fit in a `Decimal`.
+    pub fn to_unit(&self, unit: &str) -> Result<Decimal> {
+        let denomination = require_unit(
+            &self.get_currency(),
+            unit,
+            FinDenomination::builtin(&self.get_currency(), unit),
+        )?;
+        self.amount_in_denomination(&denomination)
+    }
+
+    /// Like [`to_unit`](Self::to_unit), also accepting units attached to the currency in
+    /// `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`to_unit`](Self::to_unit).
+    pub fn to_unit_with(&self, unit: &str, registry: &FinMoneyCurrencyRegistry) -> Result<Decimal> {
+        let currency = self.get_currency();
+        let denomination = require_unit(&currency, unit, registry.get_unit(&currency, unit))?;
+        self.amount_in_denomination(&denomination)
+    }
+
+    fn from_denomination(
+        amount: Decimal,
+        denomination: &FinDenomination,
+        currency: FinMoneyCurrency,
+    ) -> Result<FinMoney> {
+        let value = amount
+            .checked_mul(denomination.factor)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        if !is_exact_product(amount, denomination.factor, value) {
+            return Err(FinMoneyError::InvalidAmount(format!(
+                "{} {} cannot be represented exactly in {}",
+                amount,
+                denomination.name,
+                currency.get_code()
+            )));
+        }
+
+        let money = FinMoney::new(value.normalize(), currency);
+        money.check_base_units()?;
+        Ok(money)
+    }
+
+    fn amount_in_denomination(&self, denomination: &FinDenomination) -> Result<Decimal> {
+        self.check_base_units()?;
+
+        let amount = self.get_amount();
+        let value = amount
+            .checked_div(denomination.factor)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?
+            .normalize();
+        if !is_exact_product(value, denomination.factor, amount) {
+            return Err(FinMoneyError::InvalidAmount(format!(
+                "{} cannot be expressed exactly in {}",
+                self, denomination.name
+            )));
+        }
+        Ok(value)
+    }
+
+    fn check_base_units(&self) -> Result<()> {
+        let precision = u32::from(self.get_precision());
+        if self.get_amount().normalize().scale() > precision {
+            return Err(FinMoneyError::InvalidAmount(format!(
+                "{} is not a whole number of base units ({} decimal places)",
+                self, precision
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn require_unit(
+    currency: &FinMoneyCurrency,
+    name: &str,
+    denomination: Option<FinDenomination>,
+) -> Result<FinDenomination> {
+    denomination.ok_or_else(|| {
+        FinMoneyError::InvalidUnit(format!("{} has no unit {}", currency.get_code(), name))
+    })
+}
@@ -1,9 +1,13 @@
 //! Error types for the finmoney library.
 
+use rust_decimal::Decimal;
 use std::fmt;
 
 /// Errors that can occur during money operations.
+///
+/// New variants may be added in minor releases, so matches must include a wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum FinMoneyError {
     /// Attempted to perform an operation between different currencies.
     CurrencyMismatch {
@@ -11,6 +15,8 @@ pub enum FinMoneyError {
         expected: String,
         /// The actual currency code that was provided.
         actual: String,
+        /// Position of the offending element when aggregating a sequence, if applicable.
+        index: Option<usize>,
     },
     /// Attempted division by zero.
     DivisionByZero,
@@ -26,16 +32,66 @@ pub enum FinMoneyError {
     ArithmeticOverflow,
     /// Invalid amount (e.g., NaN or infinite values).
     InvalidAmount(String),
+    /// A currency definition conflicts with one already registered.
+    CurrencyConflict {
+        /// The registered currency definition.
+        existing: String,
+        /// The currency definition that was rejected.
+        rejected: String,
+    },
+    /// Configuration input could not be read or parsed.
+    InvalidConfig(String),
+    /// Allocation parameters are invalid (e.g., no ratios, or all ratios zero).
+    InvalidAllocation(String),
+    /// No exchange rate is available between two currencies.
+    MissingExchangeRate {
+        /// The currency code being converted from.
+        base: String,
+        /// The currency code being converted to.
+        quote: String,
+    },
+    /// Exchange rate is invalid (must be positive).
+    InvalidExchangeRate(Decimal),
+    /// A two-sided quote has a bid above its ask.
+    CrossedQuote {
+        /// The bid price.
+        bid: Decimal,
+        /// The ask price.
+        ask: Decimal,
+    },
+    /// Calendar date is invalid or out of range.
+    InvalidDate(String),
+    /// Currency pair cannot be parsed or names the same currency twice.
+    InvalidCurrencyPair(String),
+    /// Currency is not registered.
+    UnknownCurrency(String),
+    /// Currency has no successor to redenominate into.
+    NoSuccessorCurrency(String),
+    /// Denomination unit is unknown for the currency or has an invalid definition.
+    InvalidUnit(String),
 }
 
 impl fmt::Display for FinMoneyError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            FinMoneyError::CurrencyMismatch { expected, actual } => write!(
+            FinMoneyError::CurrencyMismatch {
+                expected,
+                actual,
+                index: None,
+            } => write!(
                 f,
                 "Currency mismatch: expected {}, got {}",
                 expected, actual
             ),
+            FinMoneyError::CurrencyMismatch {
+                expected,
+                actual,
+                index: Some(index),
+            } => write!(
+                f,
+                "Currency mismatch at index {}: expected {}, got {}",
+                index, expected, actual
+            ),
             FinMoneyError::DivisionByZero => write!(f, "Division by zero"),
             FinMoneyError::InvalidPrecision(p) => {
                 write!(f, "Invalid precision: {} (must be <= 28)", p)
@@ -49,6 +105,31 @@ impl fmt::Display for FinMoneyError {
             }
             FinMoneyError::ArithmeticOverflow => write!(f, "Arithmetic overflow occurred"),
             FinMoneyError::InvalidAmount(msg) => write!(f, "Invalid amount: {}", msg),
+            FinMoneyError::CurrencyConflict { existing, rejected } => write!(
+                f,
+                "Currency conflict: {} conflicts with registered {}",
+                rejected, existing
+            ),
+            FinMoneyError::InvalidConfig(msg) => write!(f, "Invalid configuration: {}", msg),
+            FinMoneyError::InvalidAllocation(msg) => write!(f, "Invalid allocation: {}", msg),
+            FinMoneyError::MissingExchangeRate { base, quote } => {
+                write!(f, "No exchange rate from {} to {}", base, quote)
+            }
+            FinMoneyError::InvalidExchangeRate(rate) => {
+                write!(f, "Invalid exchange rate: {} (must be positive)", rate)
+            }
+            FinMoneyError::CrossedQuote { bid, ask } => {
+                write!(f, "Crossed quote: bid {} is above ask {}", bid, ask)
+            }
+            FinMoneyError::InvalidDate(date) => write!(f, "Invalid date: {}", date),
+            FinMoneyError::InvalidCurrencyPair(pair) => {
+                write!(f, "Invalid currency pair: {}", pair)
+            }
+            FinMoneyError::UnknownCurrency(code) => write!(f, "Unknown currency: {}", code),
+            FinMoneyError::NoSuccessorCurrency(code) => {
+                write!(f, "Currency {} has no successor", code)
+            }
+            FinMoneyError::InvalidUnit(msg) => write!(f, "Invalid denomination unit: {}", msg),
         }
     }
 }
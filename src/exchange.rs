@@ -0,0 +1,167 @@
+//! Exchange rates and currency conversion.
+
+use crate::{FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
+use rust_decimal::Decimal;
+use std::fmt;
+
+/// The price of one unit of a base currency in a quote currency.
+///
+/// A `EUR/USD` rate of `1.0850` converts 1 EUR into 1.0850 USD. Rates are always positive, and
+/// a rate between a currency and itself is always 1. Deserialization applies the same checks
+/// as [`new`](Self::new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawExchangeRate"))]
+pub struct FinExchangeRate {
+    base: FinMoneyCurrency,
+    quote: FinMoneyCurrency,
+    rate: Decimal,
+}
+
+impl FinExchangeRate {
+    /// Creates a new exchange rate from `base` to `quote`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidExchangeRate` if `rate` is zero or negative, or if `base`
+    /// and `quote` are the same currency and `rate` is not 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinExchangeRate, FinMoney, FinMoneyCurrency, FinMoneyRoundingStrategy};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let eur_usd = FinExchangeRate::new(FinMoneyCurrency::EUR, FinMoneyCurrency::USD, dec!(1.0850))?;
+    /// let euros = FinMoney::new(dec!(100), FinMoneyCurrency::EUR);
+    ///
+    /// let dollars = eur_usd.convert(&euros, FinMoneyRoundingStrategy::default())?;
+    /// assert_eq!(dollars, FinMoney::new(dec!(108.50), FinMoneyCurrency::USD));
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn new(
+        base: FinMoneyCurrency,
+        quote: FinMoneyCurrency,
+        rate: Decimal,
+    ) -> Result<Self, FinMoneyError> {
+        if rate <= Decimal::ZERO || (base.is_same_currency(&quote) && rate != Decimal::ONE) {
+            return Err(FinMoneyError::InvalidExchangeRate(rate));
+        }
+        Ok(Self { base, quote, rate })
+    }
+
+    /// Returns the currency being priced.
+    pub fn get_base(&self) -> FinMoneyCurrency {
+        self.base
+    }
+
+    /// Returns the currency the price is expressed in.
+    pub fn get_quote(&self) -> FinMoneyCurrency {
+        self.quote
+    }
+
+    /// Returns the number of quote units per base unit.
+    pub fn get_rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Converts an amount in the base currency into the quote currency.
+    ///
+    /// The result is rounded to the quote currency's precision using `strategy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` if `money` is not in the base currency.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn convert(
+        &self,
+        money: &FinMoney,
+        strategy: FinMoneyRoundingStrategy,
+    ) -> Result<FinMoney, FinMoneyError> {
+        let currency = money.get_currency();
+        if !currency.is_same_currency(&self.base) {
+            return Err(FinMoneyError::CurrencyMismatch {
+                expected: self.base.get_code().to_string(),
+                actual: currency.get_code().to_string(),
+                index: None,
+            });
+        }
+        let amount = money
+            .get_amount()
+            .checked_mul(self.rate)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        Ok(FinMoney::new(amount, self.quote)
+            .round_dp_with_strategy(u32::from(self.quote.get_precision()), strategy))
+    }
+
+    /// Returns the rate from the quote currency back to the base currency.
+    ///
+    /// The reciprocal is rounded to `dp` decimal places using `strategy`, so the caller decides
+    /// how much precision the inverted rate carries.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidPrecision` if `dp` > 28.
+    /// Returns `FinMoneyError::InvalidExchangeRate` if the reciprocal rounds to zero.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the reciprocal is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinExchangeRate, FinMoneyCurrency, FinMoneyRoundingStrategy};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let eur_usd = FinExchangeRate::new(FinMoneyCurrency::EUR, FinMoneyCurrency::USD, dec!(1.0850))?;
+    /// let usd_eur = eur_usd.inverse(6, FinMoneyRoundingStrategy::MidpointNearestEven)?;
+    ///
+    /// assert_eq!(usd_eur.get_base(), FinMoneyCurrency::USD);
+    /// assert_eq!(usd_eur.get_rate(), dec!(0.921659));
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn inverse(
+        &self,
+        dp: u32,
+        strategy: FinMoneyRoundingStrategy,
+    ) -> Result<FinExchangeRate, FinMoneyError> {
+        if dp > 28 {
+            return Err(FinMoneyError::InvalidPrecision(dp));
+        }
+        let inverted = Decimal::ONE
+            .checked_div(self.rate)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?
+            .round_dp_with_strategy(dp, strategy.to_decimal_strategy());
+        FinExchangeRate::new(self.quote, self.base, inverted)
+    }
+}
+
+/// The serialized form of [`FinExchangeRate`], validated by [`FinExchangeRate::new`] on
+/// deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawExchangeRate {
+    base: FinMoneyCurrency,
+    quote: FinMoneyCurrency,
+    rate: Decimal,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawExchangeRate> for FinExchangeRate {
+    type Error = FinMoneyError;
+
+    fn try_from(raw: RawExchangeRate) -> Result<Self, Self::Error> {
+        FinExchangeRate::new(raw.base, raw.quote, raw.rate)
+    }
+}
+
+impl fmt::Display for FinExchangeRate {
+    /// Formats the rate as `BASE/QUOTE rate`, e.g. `EUR/USD 1.0850`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} {}",
+            self.base.get_code(),
+            self.quote.get_code(),
+            self.rate
+        )
+    }
+}
@@ -0,0 +1,182 @@
+//! FX forward pricing from forward points or covered interest parity.
+
+use crate::{FinDate, FinExchangeRate, FinMoneyError, FinPairConvention};
+use rust_decimal::Decimal;
+
+/// Conventions for turning a period between two dates into a fraction of a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FinDayCount {
+    /// Actual days / 360 (USD, EUR money markets).
+    Act360,
+    /// Actual days / 365 (GBP, AUD money markets).
+    Act365Fixed,
+    /// 30/360 bond basis: every month has 30 days.
+    Thirty360,
+}
+
+impl FinDayCount {
+    /// Returns the number of days between `start` and `end` under this convention (negative if
+    /// `end` is earlier).
+    pub fn days_between(&self, start: FinDate, end: FinDate) -> i64 {
+        match self {
+            FinDayCount::Act360 | FinDayCount::Act365Fixed => start.days_until(end),
+            FinDayCount::Thirty360 => {
+                let d1 = start.get_day().min(30);
+                let d2 = if end.get_day() == 31 && d1 == 30 {
+                    30
+                } else {
+                    end.get_day()
+                };
+                360 * (i64::from(end.get_year()) - i64::from(start.get_year()))
+                    + 30 * (i64::from(end.get_month()) - i64::from(start.get_month()))
+                    + (i64::from(d2) - i64::from(d1))
+            }
+        }
+    }
+
+    /// Returns the number of days in a year under this convention.
+    pub fn year_basis(&self) -> u32 {
+        match self {
+            FinDayCount::Act360 | FinDayCount::Thirty360 => 360,
+            FinDayCount::Act365Fixed => 365,
+        }
+    }
+
+    /// Returns the fraction of a year between `start` and `end` (negative if `end` is earlier).
+    pub fn year_fraction(&self, start: FinDate, end: FinDate) -> Decimal {
+        Decimal::from(self.days_between(start, end)) / Decimal::from(self.year_basis())
+    }
+}
+
+/// A simple annual interest rate with its day-count convention, e.g. a deposit rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinInterestRate {
+    rate: Decimal,
+    day_count: FinDayCount,
+}
+
+impl FinInterestRate {
+    /// Creates a new interest rate from an annual rate as a fraction (`0.05` for 5%).
+    pub fn new(rate: Decimal, day_count: FinDayCount) -> Self {
+        Self { rate, day_count }
+    }
+
+    /// Returns the annual rate as a fraction (`0.05` for 5%).
+    pub fn get_rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Returns how the period is converted into a fraction of a year.
+    pub fn get_day_count(&self) -> FinDayCount {
+        self.day_count
+    }
+
+    /// Returns the simple-interest growth factor `1 + rate * days / basis` over the period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn growth_factor(&self, start: FinDate, end: FinDate) -> Result<Decimal, FinMoneyError> {
+        let days = Decimal::from(self.day_count.days_between(start, end));
+        let basis = Decimal::from(self.day_count.year_basis());
+        self.rate
+            .checked_mul(days)
+            .and_then(|v| v.checked_div(basis))
+            .and_then(|interest| Decimal::ONE.checked_add(interest))
+            .ok_or(FinMoneyError::ArithmeticOverflow)
+    }
+}
+
+impl FinExchangeRate {
+    /// Returns the outright forward rate from this spot rate plus forward `points`.
+    ///
+    /// Points are quoted in pips of the pair, so they are scaled by the convention's pip size:
+    /// 25 points on EUR/USD add `0.0025`, on USD/JPY they add `0.25`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidExchangeRate` if the forward rate is not positive.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinCurrencyPair, FinExchangeRate, FinPairConventions, iso4217};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let conventions = FinPairConventions::market();
+    /// let pair: FinCurrencyPair = "USD/JPY".parse()?;
+    ///
+    /// let spot = FinExchangeRate::new(iso4217::USD, iso4217::JPY, dec!(150.25))?;
+    /// let forward = spot.forward_from_points(dec!(-85.5), &conventions.get_convention(pair))?;
+    /// assert_eq!(forward.get_rate(), dec!(149.395));
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn forward_from_points(
+        &self,
+        points: Decimal,
+        convention: &FinPairConvention,
+    ) -> Result<FinExchangeRate, FinMoneyError> {
+        let forward = points
+            .checked_mul(convention.get_pip_size())
+            .and_then(|delta| self.get_rate().checked_add(delta))
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        FinExchangeRate::new(self.get_base(), self.get_quote(), forward)
+    }
+
+    /// Returns the forward rate for `maturity` implied by covered interest parity:
+    /// `spot * (1 + quote_rate * t_quote) / (1 + base_rate * t_base)`, where each year fraction
+    /// uses that currency's day count.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidDate` if `maturity` is before `spot_date`.
+    /// Returns `FinMoneyError::DivisionByZero` if the base growth factor is zero.
+    /// Returns `FinMoneyError::InvalidExchangeRate` if the forward rate is not positive.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinDate, FinDayCount, FinExchangeRate, FinInterestRate, iso4217};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let spot = FinExchangeRate::new(iso4217::EUR, iso4217::USD, dec!(1.08))?;
+    /// let forward = spot.forward_from_interest(
+    ///     FinInterestRate::new(dec!(0.04), FinDayCount::Act360),
+    ///     FinInterestRate::new(dec!(0.05), FinDayCount::Act360),
+    ///     FinDate::new(2024, 1, 2)?,
+    ///     FinDate::new(2024, 7, 1)?,
+    /// )?;
+    /// // 1.08 * (1 + 0.05 * 181/360) / (1 + 0.04 * 181/360)
+    /// assert_eq!(forward.get_rate().round_dp(6), dec!(1.085323));
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn forward_from_interest(
+        &self,
+        base_rate: FinInterestRate,
+        quote_rate: FinInterestRate,
+        spot_date: FinDate,
+        maturity: FinDate,
+    ) -> Result<FinExchangeRate, FinMoneyError> {
+        if maturity < spot_date {
+            return Err(FinMoneyError::InvalidDate(format!(
+                "maturity {} is before spot date {}",
+                maturity, spot_date
+            )));
+        }
+        let base_growth = base_rate.growth_factor(spot_date, maturity)?;
+        let quote_growth = quote_rate.growth_factor(spot_date, maturity)?;
+        if base_growth.is_zero() {
+            return Err(FinMoneyError::DivisionByZero);
+        }
+        let forward = self
+            .get_rate()
+            .checked_mul(quote_growth)
+            .and_then(|v| v.checked_div(base_growth))
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        FinExchangeRate::new(self.get_base(), self.get_quote(), forward)
+    }
+}
@@ -0,0 +1,358 @@
+//! Time-indexed exchange rate histories with as-of lookup.
+
+use crate::{
+    FinDate, FinExchangeRate, FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy,
+    FinRateTable, FinTriangulation,
+};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+
+/// A point in time that rates can be keyed by.
+///
+/// Implemented for [`FinDate`] (daily rates) and `i64` (e.g. Unix timestamps).
+pub trait FinTimeKey: Copy + Ord {
+    /// Returns the position of this key on a linear time axis, used for interpolation and gap
+    /// detection: days since 1970-01-01 for [`FinDate`], the value itself for `i64`.
+    fn to_ordinal(&self) -> i64;
+}
+
+impl FinTimeKey for FinDate {
+    fn to_ordinal(&self) -> i64 {
+        self.to_days_since_epoch()
+    }
+}
+
+impl FinTimeKey for i64 {
+    fn to_ordinal(&self) -> i64 {
+        *self
+    }
+}
+
+/// How [`FinRateHistory`] picks a rate for a point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FinAsOf {
+    /// Only a rate observed exactly at the requested time.
+    Exact,
+    /// The latest rate observed at or before the requested time.
+    #[default]
+    Previous,
+    /// A rate linearly interpolated between the surrounding observations. Never extrapolates.
+    Interpolated,
+}
+
+/// A stretch without observations, between two consecutive observations of a pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FinRateGap<K> {
+    /// The last observation before the gap.
+    pub from: K,
+    /// The first observation after the gap.
+    pub to: K,
+}
+
+#[derive(Debug, Clone)]
+struct Series<K> {
+    base: FinMoneyCurrency,
+    quote: FinMoneyCurrency,
+    rates: BTreeMap<K, Decimal>,
+}
+
+/// Exchange rates per currency pair, keyed by time.
+///
+/// Rates are stored in the direction they were observed; the inverse direction is derived on
+/// lookup.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinAsOf, FinDate, FinExchangeRate, FinRateHistory, iso4217};
+/// use rust_decimal_macros::dec;
+///
+/// let mut history = FinRateHistory::new();
+/// let rate = |r| FinExchangeRate::new(iso4217::EUR, iso4217::USD, r);
+/// history.insert(FinDate::new(2024, 1, 1)?, rate(dec!(1.10))?);
+/// history.insert(FinDate::new(2024, 1, 5)?, rate(dec!(1.14))?);
+///
+/// let at = FinDate::new(2024, 1, 3)?;
+/// let previous = history.get_rate(iso4217::EUR, iso4217::USD, at, FinAsOf::Previous)?;
+/// let interpolated = history.get_rate(iso4217::EUR, iso4217::USD, at, FinAsOf::Interpolated)?;
+/// assert_eq!(previous.get_rate(), dec!(1.10));
+/// assert_eq!(interpolated.get_rate(), dec!(1.12));
+/// # Ok::<(), finmoney::FinMoneyError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct FinRateHistory<K = FinDate> {
+    series: HashMap<(i32, i32), Series<K>>,
+}
+
+impl<K> Default for FinRateHistory<K> {
+    fn default() -> Self {
+        Self {
+            series: HashMap::new(),
+        }
+    }
+}
+
+impl<K: FinTimeKey> FinRateHistory<K> {
+    /// Creates an empty history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `rate` as observed at `at`, returning the rate it replaces, if any.
+    pub fn insert(&mut self, at: K, rate: FinExchangeRate) -> Option<Decimal> {
+        let (base, quote) = (rate.get_base(), rate.get_quote());
+        self.series
+            .entry((base.get_id(), quote.get_id()))
+            .or_insert_with(|| Series {
+                base,
+                quote,
+                rates: BTreeMap::new(),
+            })
+            .rates
+            .insert(at, rate.get_rate())
+    }
+
+    /// Returns the number of observations stored for `base`/`quote` in that direction.
+    pub fn pair_len(&self, base: FinMoneyCurrency, quote: FinMoneyCurrency) -> usize {
+        self.series
+            .get(&(base.get_id(), quote.get_id()))
+            .map_or(0, |series| series.rates.len())
+    }
+
+    /// Returns `true` if no rates are stored.
+    pub fn is_empty(&self) -> bool {
+        self.series.is_empty()
+    }
+
+    /// Returns the rate from `base` to `quote` valid at `at` according to `as_of`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::MissingExchangeRate` if neither direction of the pair has a rate
+    /// for `at`.
+    pub fn get_rate(
+        &self,
+        base: FinMoneyCurrency,
+        quote: FinMoneyCurrency,
+        at: K,
+        as_of: FinAsOf,
+    ) -> Result<FinExchangeRate, FinMoneyError> {
+        if let Some(series) = self.series.get(&(base.get_id(), quote.get_id())) {
+            if let Some(rate) = lookup(&series.rates, at, as_of)? {
+                return FinExchangeRate::new(series.base, series.quote, rate);
+            }
+        }
+        if let Some(series) = self.series.get(&(quote.get_id(), base.get_id())) {
+            if let Some(rate) = lookup(&series.rates, at, as_of)? {
+                return FinExchangeRate::new(series.base, series.quote, rate)?
+                    .inverse(28, FinMoneyRoundingStrategy::MidpointNearestEven);
+            }
+        }
+        Err(FinMoneyError::MissingExchangeRate {
+            base: base.get_code().to_string(),
+            quote: quote.get_code().to_string(),
+        })
+    }
+
+    /// Converts `money` into `quote` at the rate valid at `at`, rounding to the quote
+    /// currency's precision with `strategy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::MissingExchangeRate` if no rate is valid at `at`.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn convert(
+        &self,
+        money: &FinMoney,
+        quote: FinMoneyCurrency,
+        at: K,
+        as_of: FinAsOf,
+        strategy: FinMoneyRoundingStrategy,
+    ) -> Result<FinMoney, FinMoneyError> {
+        self.get_rate(money.get_currency(), quote, at, as_of)?
+            .convert(money, strategy)
+    }
+
+    /// Returns a rate table holding, for every pair, the rate valid at `at`.
+    ///
+    /// Pairs without a rate at `at` are left out. The table can triangulate across pairs like
+    /// any other [`FinRateTable`].
+    pub fn snapshot(&self, at: K, as_of: FinAsOf, triangulation: FinTriangulation) -> FinRateTable {
+        let mut table = FinRateTable::new(triangulation);
+        for series in self.series.values() {
+            let rate = lookup(&series.rates, at, as_of)
+                .ok()
+                .flatten()
+                .and_then(|rate| FinExchangeRate::new(series.base, series.quote, rate).ok());
+            if let Some(rate) = rate {
+                table.insert(rate);
+            }
+        }
+        table
+    }
+
+    /// Returns every stretch between consecutive observations of `base`/`quote` that spans more
+    /// than `max_step` on the time axis (days for [`FinDate`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinDate, FinExchangeRate, FinRateGap, FinRateHistory, iso4217};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let mut history = FinRateHistory::new();
+    /// let rate = FinExchangeRate::new(iso4217::EUR, iso4217::USD, dec!(1.1))?;
+    /// for day in [1, 2, 3, 8, 9] {
+    ///     history.insert(FinDate::new(2024, 1, day)?, rate);
+    /// }
+    ///
+    /// let gaps = history.gaps(iso4217::EUR, iso4217::USD, 1);
+    /// assert_eq!(
+    ///     gaps,
+    ///     vec![FinRateGap { from: FinDate::new(2024, 1, 3)?, to: FinDate::new(2024, 1, 8)? }]
+    /// );
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn gaps(
+        &self,
+        base: FinMoneyCurrency,
+        quote: FinMoneyCurrency,
+        max_step: i64,
+    ) -> Vec<FinRateGap<K>> {
+        let Some(series) = self.series.get(&(base.get_id(), quote.get_id())) else {
+            return Vec::new();
+        };
+        let keys: Vec<K> = series.rates.keys().copied().collect();
+        keys.windows(2)
+            .filter(|pair| ordinal_distance(pair[0], pair[1]) > i128::from(max_step))
+            .map(|pair| FinRateGap {
+                from: pair[0],
+                to: pair[1],
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "config")]
+impl<K> FinRateHistory<K>
+where
+    K: FinTimeKey + std::str::FromStr,
+    K::Err: std::fmt::Display,
+{
+    /// Loads `base`/`quote` observations from CSV with a header row and `at,rate` columns
+    /// (feature `config`).
+    ///
+    /// Loading is atomic: if any row is invalid, nothing is inserted and every invalid row is
+    /// reported with its line number.
+    ///
+    /// ```text
+    /// at,rate
+    /// 2024-01-01,1.1037
+    /// 2024-01-02,1.0942
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns one `FinMoneyConfigError` per row that cannot be read, whose key cannot be
+    /// parsed (`FinMoneyError::InvalidConfig`) or whose rate is not positive
+    /// (`FinMoneyError::InvalidExchangeRate`).
+    pub fn load_csv(
+        &mut self,
+        base: FinMoneyCurrency,
+        quote: FinMoneyCurrency,
+        input: &str,
+    ) -> crate::config::ConfigResult<usize> {
+        use crate::config::FinMoneyConfigError;
+
+        #[derive(serde::Deserialize)]
+        struct Row {
+            at: String,
+            rate: Decimal,
+        }
+
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(input.as_bytes());
+        let headers = reader
+            .headers()
+            .map_err(|e| {
+                vec![FinMoneyConfigError {
+                    line: Some(1),
+                    error: FinMoneyError::InvalidConfig(e.to_string()),
+                }]
+            })?
+            .clone();
+
+        let mut rows = Vec::new();
+        let mut errors = Vec::new();
+        for record in reader.records() {
+            let parsed = match record {
+                Ok(record) => {
+                    let line = record.position().map(|p| p.line() as usize);
+                    record
+                        .deserialize::<Row>(Some(&headers))
+                        .map_err(|e| FinMoneyError::InvalidConfig(e.to_string()))
+                        .and_then(|row| {
+                            let at = row
+                                .at
+                                .parse::<K>()
+                                .map_err(|e| FinMoneyError::InvalidConfig(e.to_string()))?;
+                            Ok((at, FinExchangeRate::new(base, quote, row.rate)?))
+                        })
+                        .map_err(|error| FinMoneyConfigError { line, error })
+                }
+                Err(e) => Err(FinMoneyConfigError {
+                    line: e.position().map(|p| p.line() as usize),
+                    error: FinMoneyError::InvalidConfig(e.to_string()),
+                }),
+            };
+            match parsed {
+                Ok(row) => rows.push(row),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        let count = rows.len();
+        for (at, rate) in rows {
+            self.insert(at, rate);
+        }
+        Ok(count)
+    }
+}
+
+/// Returns `to - from` in ordinal units, widened so far-apart `i64` timestamps cannot overflow.
+fn ordinal_distance<K: FinTimeKey>(from: K, to: K) -> i128 {
+    i128::from(to.to_ordinal()) - i128::from(from.to_ordinal())
+}
+
+fn lookup<K: FinTimeKey>(
+    rates: &BTreeMap<K, Decimal>,
+    at: K,
+    as_of: FinAsOf,
+) -> Result<Option<Decimal>, FinMoneyError> {
+    if let Some(rate) = rates.get(&at) {
+        return Ok(Some(*rate));
+    }
+    let previous = rates.range(..at).next_back();
+    match as_of {
+        FinAsOf::Exact => Ok(None),
+        FinAsOf::Previous => Ok(previous.map(|(_, rate)| *rate)),
+        FinAsOf::Interpolated => {
+            let (Some((k0, r0)), Some((k1, r1))) = (previous, rates.range(at..).next()) else {
+                return Ok(None);
+            };
+            let span = Decimal::from(ordinal_distance(*k0, *k1));
+            let elapsed = Decimal::from(ordinal_distance(*k0, at));
+            (*r1 - *r0)
+                .checked_mul(elapsed)
+                .and_then(|delta| delta.checked_div(span))
+                .and_then(|delta| r0.checked_add(delta))
+                .map(Some)
+                .ok_or(FinMoneyError::ArithmeticOverflow)
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Built-in ISO 4217 currency table.
+//!
+//! Every active currency from ISO 4217 list one that has a defined number of minor units is
+//! exposed as a constant (e.g. [`JPY`], [`KWD`]) and can be looked up at runtime with
+//! [`FinMoneyCurrency::from_iso_alpha`] or [`FinMoneyCurrency::from_iso_numeric`].
+//!
+//! The constants use the ISO numeric code as their currency id, so `iso4217::USD` has id 840.
+//! The [`FinMoneyCurrency::USD`] and [`FinMoneyCurrency::EUR`] constants are aliases of
+//! [`USD`] and [`EUR`].
+//!
+//! Codes whose minor unit is "N.A." in ISO 4217 (precious metals, SDR, testing and
+//! "no currency" codes) are not included. Names are the official English names,
+//! transliterated to ASCII where needed (e.g. "Zloty", "Cordoba Oro").
+//!
+//! The table is generated at build time from `data/iso4217.csv`, one
+//! `code,numeric,minor_units,name` row per currency; update that file when ISO 4217 changes.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use finmoney::{FinMoneyCurrency, iso4217};
+//!
+//! let yen = FinMoneyCurrency::from_iso_alpha("JPY").unwrap();
+//! assert_eq!(yen, iso4217::JPY);
+//! assert_eq!(yen.get_precision(), 0);
+//!
+//! let dinar = FinMoneyCurrency::from_iso_numeric(414).unwrap();
+//! assert_eq!(dinar.get_code(), "KWD");
+//! assert_eq!(dinar.get_precision(), 3);
+//! ```
+
+use crate::FinMoneyCurrency;
+
+/// Generates one constant per row plus the sorted [`CURRENCIES`] slice.
+///
+/// The build script emits the rows sorted by alphabetic code:
+/// [`FinMoneyCurrency::from_iso_alpha`] relies on it for binary search.
+macro_rules! iso4217_table {
+    ($($code:ident => ($numeric:literal, $minor:literal, $name:literal)),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                $name, " (`", stringify!($code), "`, numeric ", stringify!($numeric),
+                ", ", stringify!($minor), " minor units)."
+            )]
+            pub const $code: FinMoneyCurrency = FinMoneyCurrency::from_static(
+                $numeric,
+                stringify!($code),
+                Some($name),
+                $minor,
+            );
+        )*
+
+        /// All currencies of the table, sorted by alphabetic code.
+        pub const CURRENCIES: &[FinMoneyCurrency] = &[$($code),*];
+    };
+}
+
+// Generated by `build.rs` from `data/iso4217.csv`.
+include!(concat!(env!("OUT_DIR"), "/iso4217_table.rs"));
+
+impl FinMoneyCurrency {
+    /// Looks up an ISO 4217 currency by its alphabetic code (e.g. "JPY").
+    ///
+    /// The lookup is ASCII case-insensitive. Returns `None` if the code is not part of the
+    /// built-in [`iso4217`](crate::iso4217) table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::FinMoneyCurrency;
+    ///
+    /// let kwd = FinMoneyCurrency::from_iso_alpha("kwd").unwrap();
+    /// assert_eq!(kwd.get_code(), "KWD");
+    /// assert_eq!(kwd.get_precision(), 3);
+    /// assert!(FinMoneyCurrency::from_iso_alpha("XYZ").is_none());
+    /// ```
+    pub fn from_iso_alpha(code: &str) -> Option<FinMoneyCurrency> {
+        let code = code.to_ascii_uppercase();
+        CURRENCIES
+            .binary_search_by(|c| c.get_code().cmp(code.as_str()))
+            .ok()
+            .map(|idx| CURRENCIES[idx])
+    }
+
+    /// Looks up an ISO 4217 currency by its numeric code (e.g. 392 for JPY).
+    ///
+    /// Returns `None` if the code is not part of the built-in [`iso4217`](crate::iso4217) table.
+    pub fn from_iso_numeric(numeric: u16) -> Option<FinMoneyCurrency> {
+        CURRENCIES
+            .iter()
+            .find(|c| c.get_id() == i32::from(numeric))
+            .copied()
+    }
+}
@@ -0,0 +1,145 @@
+//! Iterator aggregation of `FinMoney` values.
+
+use crate::{FinMoney, FinMoneyCurrency, FinMoneyError};
+use rust_decimal::Decimal;
+use std::borrow::Borrow;
+
+/// Extension methods for iterators over `FinMoney` (or `&FinMoney`) values.
+///
+/// Every element's currency is verified; the first mismatch is reported as
+/// `FinMoneyError::CurrencyMismatch` with the element's position in `index`. Empty iterators
+/// are handled explicitly: methods without a currency argument return `Ok(None)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyIteratorExt};
+/// use rust_decimal_macros::dec;
+///
+/// let usd = FinMoneyCurrency::USD;
+/// let fills = vec![FinMoney::new(dec!(10), usd), FinMoney::new(dec!(2.5), usd)];
+///
+/// let total = fills.iter().try_sum()?.unwrap();
+/// assert_eq!(total.get_amount(), dec!(12.5));
+///
+/// let none: Vec<FinMoney> = Vec::new();
+/// assert_eq!(none.iter().try_sum()?, None);
+/// assert_eq!(none.iter().try_sum_in(usd)?, FinMoney::zero(usd));
+/// # Ok::<(), FinMoneyError>(())
+/// ```
+pub trait FinMoneyIteratorExt: Iterator + Sized
+where
+    Self::Item: Borrow<FinMoney>,
+{
+    /// Sums all elements in the currency of the first element.
+    ///
+    /// Returns `Ok(None)` for an empty iterator.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` (with `index`) if an element's currency differs
+    /// from the first element's. Returns `FinMoneyError::ArithmeticOverflow` if the sum is out
+    /// of range.
+    fn try_sum(mut self) -> Result<Option<FinMoney>, FinMoneyError> {
+        match self.next() {
+            Some(first) => {
+                let first = *first.borrow();
+                sum_from(self, first, 1).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Sums all elements, requiring each of them to be in `currency`.
+    ///
+    /// Returns zero in `currency` for an empty iterator.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` (with `index`) for the first element in another
+    /// currency. Returns `FinMoneyError::ArithmeticOverflow` if the sum is out of range.
+    fn try_sum_in(self, currency: FinMoneyCurrency) -> Result<FinMoney, FinMoneyError> {
+        sum_from(self, FinMoney::zero(currency), 0)
+    }
+
+    /// Returns the smallest element, or `Ok(None)` for an empty iterator.
+    ///
+    /// If several elements are equally small, the first one is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` (with `index`) if an element's currency differs
+    /// from the first element's.
+    fn try_min(self) -> Result<Option<FinMoney>, FinMoneyError> {
+        extreme(self, |candidate, current| candidate < current)
+    }
+
+    /// Returns the largest element, or `Ok(None)` for an empty iterator.
+    ///
+    /// If several elements are equally large, the last one is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` (with `index`) if an element's currency differs
+    /// from the first element's.
+    fn try_max(self) -> Result<Option<FinMoney>, FinMoneyError> {
+        extreme(self, |candidate, current| candidate >= current)
+    }
+}
+
+impl<I> FinMoneyIteratorExt for I
+where
+    I: Iterator,
+    I::Item: Borrow<FinMoney>,
+{
+}
+
+fn check_currency(
+    reference: &FinMoney,
+    item: &FinMoney,
+    index: usize,
+) -> Result<(), FinMoneyError> {
+    if reference.is_same_currency(*item) {
+        return Ok(());
+    }
+    Err(FinMoneyError::CurrencyMismatch {
+        expected: reference.get_currency_code().to_string(),
+        actual: item.get_currency_code().to_string(),
+        index: Some(index),
+    })
+}
+
+fn sum_from<I>(iter: I, init: FinMoney, offset: usize) -> Result<FinMoney, FinMoneyError>
+where
+    I: Iterator,
+    I::Item: Borrow<FinMoney>,
+{
+    iter.enumerate().try_fold(init, |acc, (i, item)| {
+        let item = *item.borrow();
+        check_currency(&acc, &item, i + offset)?;
+        acc.checked_add_decimal(item.get_amount())
+    })
+}
+
+/// Returns the element for which `replaces(candidate, best)` last held, comparing amounts.
+fn extreme<I>(
+    mut iter: I,
+    replaces: impl Fn(Decimal, Decimal) -> bool,
+) -> Result<Option<FinMoney>, FinMoneyError>
+where
+    I: Iterator,
+    I::Item: Borrow<FinMoney>,
+{
+    let Some(first) = iter.next() else {
+        return Ok(None);
+    };
+    let mut best = *first.borrow();
+    for (i, item) in iter.enumerate() {
+        let item = *item.borrow();
+        check_currency(&best, &item, i + 1)?;
+        if replaces(item.get_amount(), best.get_amount()) {
+            best = item;
+        }
+    }
+    Ok(Some(best))
+}
@@ -7,11 +7,30 @@
 //! ## Features
 //!
 //! - **Precise arithmetic**: Built on `rust_decimal` for exact decimal calculations
-//! - **Currency safety**: Prevents mixing different currencies in operations
+//! - **Currency safety**: Prevents mixing different currencies in operations, at runtime with
+//!   `FinMoney` or at compile time with `TypedMoney<C>`
 //! - **Configurable rounding**: Multiple rounding strategies for different use cases
+//! - **ISO 4217 table**: Built-in currency constants with alphabetic/numeric lookup
+//! - **Unicode display text**: Currency names and symbols such as "Złoty" or "€", kept in the
+//!   registry so the `Copy` currency value stays ASCII
+//! - **Denomination units**: Exact conversion to and from units such as satoshi, gwei and wei
+//! - **Currency registry**: One authoritative, thread-safe source of currency definitions,
+//!   including asset classification, validity dates and redenominations
+//! - **Currency conversion**: Validated exchange rates with explicit rounding, and rate tables
+//!   with cross-rate triangulation, bid/ask quotes that round in the house's favor, and
+//!   historical rates with as-of lookup, and FX forwards from points or interest rate parity
+//! - **Fixed-rate changeovers**: Legacy currency conversion following the euro changeover rules
+//! - **Currency pairs**: Parsing and market quoting conventions (ordering, precision, pips)
+//! - **Arbitrage detection**: Profitable conversion cycles after fees, in exact decimals
+//! - **Multi-currency bags**: Accumulate amounts in several currencies and collapse them
+//! - **Allocation**: Lossless splitting by ratios and tiered waterfall distributions
 //! - **Tick handling**: Exchange-grade price/quantity rounding to valid tick sizes
-//! - **Zero panics**: All operations return `Result` types for error handling
+//! - **No panics**: Fallible operations return `Result` types for error handling. The only
+//!   exceptions are the `+=`/`-=` operators of `FinMoney` (removed by the `no-panic-ops`
+//!   feature), the deprecated `FinMoney::sqrt()`, and the operators of `TypedMoney<C>`, which
+//!   panic on overflow like integer operators
 //! - **Serde support**: Optional serialization/deserialization (feature-gated)
+//! - **Configuration loading**: Currency definitions from TOML, JSON or CSV (feature `config`)
 //!
 //! ## Quick Start
 //!
@@ -35,15 +54,55 @@
 //! # Ok::<(), finmoney::FinMoneyError>(())
 //! ```
 
+pub mod allocation;
+pub mod arbitrage;
+pub mod bag;
+pub mod changeover;
+#[cfg(feature = "config")]
+pub mod config;
 pub mod currency;
+pub mod date;
+pub mod denomination;
 pub mod error;
+pub mod exchange;
+pub mod forward;
+pub mod history;
+pub mod iso4217;
+pub mod iter;
+pub mod lifecycle;
+#[doc(hidden)]
+pub mod macros;
+pub mod metadata;
 pub mod money;
+pub mod pair;
+pub mod quote;
+pub mod rates;
+pub mod registry;
 pub mod rounding;
+pub mod typed;
+pub mod waterfall;
 
+pub use allocation::FinMoneyRemainderStrategy;
+pub use arbitrage::{FinArbitrageCycle, FinArbitrageDetector};
+pub use bag::FinMoneyBag;
+pub use changeover::FinFixedRateTable;
 pub use currency::FinMoneyCurrency;
+pub use date::FinDate;
+pub use denomination::FinDenomination;
 pub use error::FinMoneyError;
+pub use exchange::FinExchangeRate;
+pub use forward::{FinDayCount, FinInterestRate};
+pub use history::{FinAsOf, FinRateGap, FinRateHistory, FinTimeKey};
+pub use iter::FinMoneyIteratorExt;
+pub use lifecycle::FinCurrencyLifecycle;
+pub use metadata::{FinCurrencyKind, FinCurrencyMetadata};
 pub use money::FinMoney;
+pub use pair::{FinCurrencyPair, FinPairConvention, FinPairConventions};
+pub use quote::FinQuote;
+pub use rates::{FinRateTable, FinResolvedRate, FinTriangulation, RateProvider};
+pub use registry::FinMoneyCurrencyRegistry;
 pub use rounding::FinMoneyRoundingStrategy;
+pub use waterfall::{FinMoneyWaterfall, FinMoneyWaterfallResult, FinMoneyWaterfallTier};
 
 // Re-export commonly used types from dependencies
 pub use rust_decimal::Decimal;
@@ -0,0 +1,172 @@
+//! Currency validity periods and redenominations.
+
+use crate::{
+    FinDate, FinMoney, FinMoneyCurrency, FinMoneyCurrencyRegistry, FinMoneyError,
+    FinMoneyRoundingStrategy,
+};
+use rust_decimal::Decimal;
+
+/// When a currency was in use and what replaced it.
+///
+/// Lifecycles are attached to registered currencies with
+/// [`FinMoneyCurrencyRegistry::set_lifecycle`], keeping `FinMoneyCurrency` itself small.
+/// Deserialization applies the same checks as [`with_successor`](Self::with_successor).
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{
+///     FinCurrencyLifecycle, FinDate, FinMoney, FinMoneyCurrency, FinMoneyCurrencyRegistry,
+///     FinMoneyRoundingStrategy, iso4217,
+/// };
+/// use rust_decimal_macros::dec;
+///
+/// let trl = FinMoneyCurrency::new(792, "TRL", Some("Turkish Lira (old)".to_string()), 0)?;
+/// let registry = FinMoneyCurrencyRegistry::with_currencies([trl, iso4217::TRY])?;
+/// registry.set_lifecycle(
+///     trl,
+///     FinCurrencyLifecycle::new()
+///         .with_active_to(FinDate::new(2004, 12, 31)?)
+///         .with_successor(iso4217::TRY, dec!(1000000))?,
+/// )?;
+///
+/// let old = FinMoney::new(dec!(2500000), trl);
+/// let new = old.redenominate(&registry, FinMoneyRoundingStrategy::MidpointAwayFromZero)?;
+/// assert_eq!(new, FinMoney::new(dec!(2.50), iso4217::TRY));
+/// # Ok::<(), finmoney::FinMoneyError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCurrencyLifecycle"))]
+pub struct FinCurrencyLifecycle {
+    active_from: Option<FinDate>,
+    active_to: Option<FinDate>,
+    successor: Option<FinMoneyCurrency>,
+    factor: Decimal,
+}
+
+impl FinCurrencyLifecycle {
+    /// Creates a lifecycle without dates or successor: a currency that was always in use.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the first day the currency was in use.
+    pub fn with_active_from(mut self, date: FinDate) -> Self {
+        self.active_from = Some(date);
+        self
+    }
+
+    /// Sets the last day the currency was in use.
+    pub fn with_active_to(mut self, date: FinDate) -> Self {
+        self.active_to = Some(date);
+        self
+    }
+
+    /// Sets the currency that replaced this one, and how many units of this currency make one
+    /// unit of the successor (e.g. `1000000` for TRL to TRY).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidExchangeRate` if `factor` is zero or negative.
+    pub fn with_successor(
+        mut self,
+        successor: FinMoneyCurrency,
+        factor: Decimal,
+    ) -> Result<Self, FinMoneyError> {
+        if factor <= Decimal::ZERO {
+            return Err(FinMoneyError::InvalidExchangeRate(factor));
+        }
+        self.successor = Some(successor);
+        self.factor = factor;
+        Ok(self)
+    }
+
+    /// Returns the first day the currency was in use, if known.
+    pub fn get_active_from(&self) -> Option<FinDate> {
+        self.active_from
+    }
+
+    /// Returns the last day the currency was in use, if it has been withdrawn.
+    pub fn get_active_to(&self) -> Option<FinDate> {
+        self.active_to
+    }
+
+    /// Returns the successor currency and the number of units of this currency per successor
+    /// unit.
+    pub fn get_successor(&self) -> Option<(FinMoneyCurrency, Decimal)> {
+        self.successor.map(|successor| (successor, self.factor))
+    }
+
+    /// Returns `true` if the currency was in use on `date`.
+    pub fn is_active_on(&self, date: FinDate) -> bool {
+        self.active_from.is_none_or(|from| from <= date)
+            && self.active_to.is_none_or(|to| date <= to)
+    }
+}
+
+/// The serialized form of [`FinCurrencyLifecycle`], validated by
+/// [`FinCurrencyLifecycle::with_successor`] on deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawCurrencyLifecycle {
+    active_from: Option<FinDate>,
+    active_to: Option<FinDate>,
+    successor: Option<FinMoneyCurrency>,
+    factor: Decimal,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCurrencyLifecycle> for FinCurrencyLifecycle {
+    type Error = FinMoneyError;
+
+    fn try_from(raw: RawCurrencyLifecycle) -> Result<Self, Self::Error> {
+        let lifecycle = FinCurrencyLifecycle {
+            active_from: raw.active_from,
+            active_to: raw.active_to,
+            ..FinCurrencyLifecycle::default()
+        };
+        match raw.successor {
+            Some(successor) => lifecycle.with_successor(successor, raw.factor),
+            // Without a successor the factor is unused and always serialized as zero.
+            None if raw.factor.is_zero() => Ok(lifecycle),
+            None => Err(FinMoneyError::InvalidExchangeRate(raw.factor)),
+        }
+    }
+}
+
+impl FinMoney {
+    /// Converts this amount into the successor of its currency, as recorded in `registry`.
+    ///
+    /// The amount is divided by the redenomination factor and rounded to the successor's
+    /// precision with `strategy`. Only one step is taken; call again to follow a chain of
+    /// redenominations.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::UnknownCurrency` if the currency is not registered.
+    /// Returns `FinMoneyError::NoSuccessorCurrency` if the currency has no successor.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn redenominate(
+        &self,
+        registry: &FinMoneyCurrencyRegistry,
+        strategy: FinMoneyRoundingStrategy,
+    ) -> Result<FinMoney, FinMoneyError> {
+        let currency = self.get_currency();
+        if !registry.contains(&currency) {
+            return Err(FinMoneyError::UnknownCurrency(
+                currency.get_code().to_string(),
+            ));
+        }
+        let (successor, factor) = registry
+            .get_lifecycle(&currency)
+            .and_then(|lifecycle| lifecycle.get_successor())
+            .ok_or_else(|| FinMoneyError::NoSuccessorCurrency(currency.get_code().to_string()))?;
+        let amount = self
+            .get_amount()
+            .checked_div(factor)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        Ok(FinMoney::new(amount, successor)
+            .round_dp_with_strategy(u32::from(successor.get_precision()), strategy))
+    }
+}
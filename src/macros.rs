@@ -0,0 +1,243 @@
+//! Compile-time validated `currency!` and `money!` macros.
+
+/// Creates a `const` [`FinMoneyCurrency`](crate::FinMoneyCurrency), validated at compile time.
+///
+/// Accepted forms:
+///
+/// - `currency!("USDT", 6)` - code and precision
+/// - `currency!("USDT", 6, "Tether")` - code, precision and name
+/// - `currency!(id = 42, "USDT", 6, "Tether")` - with an explicit id
+///
+/// Without an explicit id, the id is derived from the code (31-bit FNV-1a hash), so the same
+/// code always yields the same id. Deriving an id for the code of a built-in currency
+/// ([`iso4217`](crate::iso4217) or the `FinMoneyCurrency` constants) fails to compile, since it
+/// would create another currency with that code; use the built-in constant instead. Different
+/// codes can hash to the same id and would then compare equal under `is_same_currency`, so a
+/// derived id that collides with a built-in currency fails to compile as well, and
+/// [`FinMoneyCurrencyRegistry::register`](crate::FinMoneyCurrencyRegistry::register) rejects a
+/// currency whose id is already registered under another code. Pass an explicit id to resolve
+/// a collision.
+///
+/// The code must be non-empty ASCII of at most 16 bytes, the name ASCII of at most 52 bytes,
+/// and the precision at most 28; anything else fails to compile.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinMoneyCurrency, currency};
+///
+/// const USDT: FinMoneyCurrency = currency!("USDT", 6, "Tether");
+/// assert_eq!(USDT.get_code(), "USDT");
+/// assert_eq!(USDT.get_name(), Some("Tether"));
+/// assert_eq!(USDT.get_precision(), 6);
+/// assert_eq!(USDT, currency!("USDT", 6, "Tether"));
+/// ```
+///
+/// ```rust,compile_fail
+/// use finmoney::{FinMoneyCurrency, currency};
+///
+/// const BAD: FinMoneyCurrency = currency!("USDT", 29);
+/// ```
+///
+/// ```rust,compile_fail
+/// use finmoney::{FinMoneyCurrency, currency};
+///
+/// // "NALDV" hashes to 242, the id of `iso4217::FJD`.
+/// const CLASH: FinMoneyCurrency = currency!("NALDV", 2);
+/// ```
+///
+/// ```rust,compile_fail
+/// use finmoney::{FinMoneyCurrency, currency};
+///
+/// // Another "USD" besides `iso4217::USD` (alias `FinMoneyCurrency::USD`).
+/// const DOLLAR: FinMoneyCurrency = currency!("USD", 2);
+/// ```
+#[macro_export]
+macro_rules! currency {
+    (id = $id:expr, $code:literal, $precision:expr $(, $name:literal)? $(,)?) => {
+        const {
+            $crate::FinMoneyCurrency::from_static(
+                $id,
+                $code,
+                $crate::__currency_name!($($name)?),
+                $precision,
+            )
+        }
+    };
+    ($code:literal, $precision:expr $(, $name:literal)? $(,)?) => {
+        $crate::currency!(
+            id = $crate::macros::__private::id_from_code($code),
+            $code,
+            $precision
+            $(, $name)?
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __currency_name {
+    () => {
+        None
+    };
+    ($name:literal) => {
+        Some($name)
+    };
+}
+
+/// Creates a [`FinMoney`](crate::FinMoney) from a decimal literal, validated at compile time.
+///
+/// The currency is a `FinMoneyCurrency` constant in scope (`money!(10.50 USD)`), a path to one
+/// (`money!(10.50 iso4217::USD)`), or any constant expression after a comma
+/// (`money!(10.50, FinMoneyCurrency::USD)`).
+///
+/// The amount must be a plain decimal literal with no more decimal places than the currency's
+/// precision, so `money!(10.505 USD)` fails to compile for a two-decimal currency. The
+/// expansion is a constant, so it can be used to initialize `const` items.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::iso4217::{JPY, USD};
+/// use finmoney::{FinMoney, FinMoneyCurrency, currency, money};
+/// use rust_decimal_macros::dec;
+///
+/// const FEE: FinMoney = money!(0.25 USD);
+/// assert_eq!(FEE.get_amount(), dec!(0.25));
+/// assert_eq!(FEE.get_currency(), USD);
+///
+/// assert_eq!(money!(-1_000 JPY).get_amount(), dec!(-1000));
+///
+/// const USDT: FinMoneyCurrency = currency!("USDT", 6);
+/// assert_eq!(money!(1.000001 USDT).get_amount(), dec!(1.000001));
+/// ```
+///
+/// ```rust,compile_fail
+/// use finmoney::iso4217::USD;
+/// use finmoney::money;
+///
+/// let too_precise = money!(10.505 USD);
+/// ```
+#[macro_export]
+macro_rules! money {
+    ($amount:literal $currency:path) => {
+        $crate::money!($amount, $currency)
+    };
+    ($amount:literal, $currency:expr) => {
+        const { $crate::macros::__private::money(stringify!($amount), $currency) }
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use crate::{FinMoney, FinMoneyCurrency};
+    use rust_decimal::Decimal;
+
+    /// Derives a stable, non-negative currency id from a code (31-bit FNV-1a); panics (at
+    /// compile time) if the code or the id belongs to a built-in currency.
+    pub const fn id_from_code(code: &str) -> i32 {
+        let bytes = code.as_bytes();
+        let mut hash: u32 = 0x811c_9dc5;
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+            i += 1;
+        }
+        let id = (hash & 0x7fff_ffff) as i32;
+
+        // `FinMoneyCurrency::USD` and `EUR` are part of the ISO table.
+        let legacy = [FinMoneyCurrency::BTC, FinMoneyCurrency::ETH];
+        assert!(
+            !collides(&legacy, id, bytes) && !collides(crate::iso4217::CURRENCIES, id, bytes),
+            "currency! code or derived id belongs to a built-in currency; use the built-in \
+             constant or pass `id = ...`"
+        );
+        id
+    }
+
+    /// Checks whether one of `currencies` has the id `id` or the code `code`.
+    const fn collides(currencies: &[FinMoneyCurrency], id: i32, code: &[u8]) -> bool {
+        let mut i = 0;
+        while i < currencies.len() {
+            if currencies[i].get_id() == id || eq_bytes(currencies[i].get_code().as_bytes(), code) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    const fn eq_bytes(a: &[u8], b: &[u8]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Builds a `FinMoney` from a stringified decimal literal; panics (at compile time) if the
+    /// literal is malformed or more precise than the currency.
+    pub const fn money(literal: &str, currency: FinMoneyCurrency) -> FinMoney {
+        let amount = parse_amount(literal);
+        assert!(
+            amount.scale() <= currency.get_precision() as u32,
+            "money! amount has more decimal places than the currency precision"
+        );
+        FinMoney::new(amount, currency)
+    }
+
+    const fn parse_amount(literal: &str) -> Decimal {
+        let bytes = literal.as_bytes();
+        let mut i = 0;
+        let negative = !bytes.is_empty() && bytes[0] == b'-';
+        if negative {
+            i += 1;
+        }
+        // `stringify!` may separate the sign from the digits.
+        while i < bytes.len() && bytes[i] == b' ' {
+            i += 1;
+        }
+
+        let mut mantissa: u128 = 0;
+        let mut scale: u32 = 0;
+        let mut seen_digit = false;
+        let mut seen_point = false;
+        while i < bytes.len() {
+            let b = bytes[i];
+            if b.is_ascii_digit() {
+                mantissa = match mantissa.checked_mul(10) {
+                    Some(m) => m + (b - b'0') as u128,
+                    None => panic!("money! amount is out of range"),
+                };
+                if seen_point {
+                    scale += 1;
+                }
+                seen_digit = true;
+            } else if b == b'.' && !seen_point {
+                seen_point = true;
+            } else if b != b'_' {
+                panic!("money! amount must be a plain decimal literal");
+            }
+            i += 1;
+        }
+
+        assert!(seen_digit, "money! amount must contain digits");
+        assert!(mantissa < 1 << 96, "money! amount is out of range");
+        assert!(scale <= 28, "money! amount has more than 28 decimal places");
+
+        Decimal::from_parts(
+            mantissa as u32,
+            (mantissa >> 32) as u32,
+            (mantissa >> 64) as u32,
+            negative,
+            scale,
+        )
+    }
+}
@@ -0,0 +1,156 @@
+//! Currency classification and descriptive metadata.
+
+use crate::FinMoneyCurrency;
+use std::fmt;
+
+/// The kind of asset a currency represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FinCurrencyKind {
+    /// Government-issued money (USD, EUR, JPY).
+    Fiat,
+    /// Native blockchain assets (BTC, ETH).
+    Crypto,
+    /// Tokens pegged to another asset (USDT, USDC).
+    Stablecoin,
+    /// Physical goods traded as a unit of account (gold, oil).
+    Commodity,
+    /// Loyalty points, miles and other non-monetary balances.
+    Points,
+    /// Index units, baskets and other derived units of account (CLF, XDR).
+    Synthetic,
+}
+
+impl fmt::Display for FinCurrencyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FinCurrencyKind::Fiat => "fiat",
+            FinCurrencyKind::Crypto => "crypto",
+            FinCurrencyKind::Stablecoin => "stablecoin",
+            FinCurrencyKind::Commodity => "commodity",
+            FinCurrencyKind::Points => "points",
+            FinCurrencyKind::Synthetic => "synthetic",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Descriptive information about a currency beyond its id, code and precision.
+///
+/// `FinMoneyCurrency` stores its name as ASCII so it stays small and `Copy`; the Unicode
+/// display name and symbol (e.g. "Złoty", "€", "円") live here instead. Metadata is attached
+/// to registered currencies with
+/// [`FinMoneyCurrencyRegistry::set_metadata`](crate::FinMoneyCurrencyRegistry::set_metadata).
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinCurrencyKind, FinCurrencyMetadata, FinMoneyCurrency, FinMoneyCurrencyRegistry};
+///
+/// let registry = FinMoneyCurrencyRegistry::with_currencies([FinMoneyCurrency::BTC])?;
+/// registry.set_metadata(
+///     FinMoneyCurrency::BTC,
+///     FinCurrencyMetadata::new(FinCurrencyKind::Crypto)
+///         .with_symbol("₿")
+///         .with_minor_unit_name("satoshi"),
+/// )?;
+///
+/// assert_eq!(registry.get_kind(&FinMoneyCurrency::BTC), Some(FinCurrencyKind::Crypto));
+/// assert_eq!(registry.get_symbol(&FinMoneyCurrency::BTC).as_deref(), Some("₿"));
+/// # Ok::<(), finmoney::FinMoneyError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinCurrencyMetadata {
+    kind: FinCurrencyKind,
+    display_name: Option<String>,
+    symbol: Option<String>,
+    iso_numeric: Option<u16>,
+    minor_unit_name: Option<String>,
+}
+
+impl FinCurrencyMetadata {
+    /// Creates metadata of the given kind without display name, symbol, ISO numeric code or
+    /// minor-unit name.
+    pub fn new(kind: FinCurrencyKind) -> Self {
+        Self {
+            kind,
+            display_name: None,
+            symbol: None,
+            iso_numeric: None,
+            minor_unit_name: None,
+        }
+    }
+
+    /// Returns metadata for a currency of the built-in [`iso4217`](crate::iso4217) table.
+    ///
+    /// The kind is `Fiat`, except for ISO fund codes (e.g. CLF, MXV, USN), which are
+    /// `Synthetic`. Returns `None` if the currency's id and code are not an ISO 4217 entry.
+    pub fn iso4217(currency: &FinMoneyCurrency) -> Option<Self> {
+        const FUNDS: [&str; 9] = [
+            "BOV", "CHE", "CHW", "CLF", "COU", "MXV", "USN", "UYI", "UYW",
+        ];
+
+        let numeric = u16::try_from(currency.get_id()).ok()?;
+        let iso = FinMoneyCurrency::from_iso_numeric(numeric)?;
+        if iso.get_code() != currency.get_code() {
+            return None;
+        }
+        let kind = if FUNDS.contains(&iso.get_code()) {
+            FinCurrencyKind::Synthetic
+        } else {
+            FinCurrencyKind::Fiat
+        };
+        Some(Self::new(kind).with_iso_numeric(numeric))
+    }
+
+    /// Sets the Unicode name shown to users (e.g. `"Złoty"`), overriding the currency's ASCII
+    /// name.
+    pub fn with_display_name(mut self, name: impl Into<String>) -> Self {
+        self.display_name = Some(name.into());
+        self
+    }
+
+    /// Sets the display symbol (e.g. `"$"`, `"€"`).
+    pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// Sets the ISO 4217 numeric code.
+    pub fn with_iso_numeric(mut self, numeric: u16) -> Self {
+        self.iso_numeric = Some(numeric);
+        self
+    }
+
+    /// Sets the name of the minor unit (e.g. `"cent"`, `"satoshi"`).
+    pub fn with_minor_unit_name(mut self, name: impl Into<String>) -> Self {
+        self.minor_unit_name = Some(name.into());
+        self
+    }
+
+    /// Returns the kind of asset.
+    pub fn get_kind(&self) -> FinCurrencyKind {
+        self.kind
+    }
+
+    /// Returns the Unicode display name, if any.
+    pub fn get_display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    /// Returns the display symbol, if any.
+    pub fn get_symbol(&self) -> Option<&str> {
+        self.symbol.as_deref()
+    }
+
+    /// Returns the ISO 4217 numeric code, if any.
+    pub fn get_iso_numeric(&self) -> Option<u16> {
+        self.iso_numeric
+    }
+
+    /// Returns the name of the minor unit, if any.
+    pub fn get_minor_unit_name(&self) -> Option<&str> {
+        self.minor_unit_name.as_deref()
+    }
+}
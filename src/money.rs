@@ -1,11 +1,13 @@
 //! Core FinMoney type and operations.
 
-use crate::{FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
+use crate::{FinMoneyCurrency, FinMoneyCurrencyRegistry, FinMoneyError, FinMoneyRoundingStrategy};
 use rust_decimal::{Decimal, MathematicalOps};
 use rust_decimal_macros::dec;
 use std::cmp::Ordering;
 use std::fmt;
-use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+#[cfg(not(feature = "no-panic-ops"))]
+use std::ops::{AddAssign, SubAssign};
 
 /// Represents a monetary value with an amount and associated currency.
 ///
@@ -47,16 +49,31 @@ impl FinMoney {
     // -- Internal Helpers --
 
     #[inline]
-    fn assert_same_currency(&self, other: Self) -> Result<(), FinMoneyError> {
+    pub(crate) fn assert_same_currency(&self, other: Self) -> Result<(), FinMoneyError> {
         if !self.currency.is_same_currency(&other.currency) {
             return Err(FinMoneyError::CurrencyMismatch {
                 expected: self.currency.get_code().to_string(),
                 actual: other.currency.get_code().to_string(),
+                index: None,
             });
         }
         Ok(())
     }
 
+    /// Computes `change * 100 / base` without panicking.
+    fn checked_percent_of(
+        change: Option<Decimal>,
+        base: Decimal,
+    ) -> Result<Decimal, FinMoneyError> {
+        if base.is_zero() {
+            return Err(FinMoneyError::DivisionByZero);
+        }
+        change
+            .and_then(|c| c.checked_mul(dec!(100)))
+            .and_then(|c| c.checked_div(base))
+            .ok_or(FinMoneyError::ArithmeticOverflow)
+    }
+
     #[inline]
     fn round_result(&self, value: Decimal, strategy: FinMoneyRoundingStrategy) -> Decimal {
         value.round_dp_with_strategy(
@@ -79,7 +96,7 @@ impl FinMoney {
     /// let FinMoney = FinMoney::new(dec!(42.50), usd);
     /// assert_eq!(FinMoney.get_amount(), dec!(42.50));
     /// ```
-    pub fn new(amount: Decimal, currency: FinMoneyCurrency) -> Self {
+    pub const fn new(amount: Decimal, currency: FinMoneyCurrency) -> Self {
         Self { amount, currency }
     }
 
@@ -133,6 +150,37 @@ impl FinMoney {
         }
     }
 
+    /// Returns the largest amount representable at the currency's precision.
+    ///
+    /// This is `Decimal::MAX` scaled down by the precision, e.g.
+    /// `792281625142643375935439503.35` for a currency with 2 decimal places. A precision above
+    /// `Decimal::MAX_SCALE` (28) is treated as 28.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinMoney, FinMoneyCurrency};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let max = FinMoney::max_value(FinMoneyCurrency::USD);
+    /// assert_eq!(max.get_amount(), dec!(792281625142643375935439503.35));
+    /// ```
+    pub fn max_value(currency: FinMoneyCurrency) -> Self {
+        let amount = Decimal::from_parts(
+            u32::MAX,
+            u32::MAX,
+            u32::MAX,
+            false,
+            u32::from(currency.get_precision()).min(Decimal::MAX_SCALE),
+        );
+        Self { amount, currency }
+    }
+
+    /// Returns the smallest (most negative) amount representable at the currency's precision.
+    pub fn min_value(currency: FinMoneyCurrency) -> Self {
+        Self::max_value(currency).negated()
+    }
+
     // -- Accessors (getters) --
 
     /// Returns the amount of FinMoney as a `Decimal`.
@@ -164,36 +212,304 @@ impl FinMoney {
         self.currency.get_code()
     }
 
+    // -- Checked Arithmetic --
+
+    /// Adds another `FinMoney` value to this one, ensuring the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` if the currencies don't match.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{Decimal, FinMoney, FinMoneyCurrency, FinMoneyError};
+    ///
+    /// let max = FinMoney::new(Decimal::MAX, FinMoneyCurrency::USD);
+    /// let one = FinMoney::new(Decimal::ONE, FinMoneyCurrency::USD);
+    /// assert_eq!(max.checked_add(one), Err(FinMoneyError::ArithmeticOverflow));
+    /// ```
+    pub fn checked_add(&self, other: FinMoney) -> Result<FinMoney, FinMoneyError> {
+        self.assert_same_currency(other)?;
+        self.checked_add_decimal(other.amount)
+    }
+
+    /// Subtracts another `FinMoney` value from this one, ensuring the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` if the currencies don't match.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn checked_sub(&self, other: FinMoney) -> Result<FinMoney, FinMoneyError> {
+        self.assert_same_currency(other)?;
+        self.checked_sub_decimal(other.amount)
+    }
+
+    /// Adds a `Decimal` amount to this `FinMoney`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn checked_add_decimal(&self, d: Decimal) -> Result<FinMoney, FinMoneyError> {
+        let amount = self
+            .amount
+            .checked_add(d)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        Ok(FinMoney::new(amount, self.currency))
+    }
+
+    /// Subtracts a `Decimal` amount from this `FinMoney`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn checked_sub_decimal(&self, d: Decimal) -> Result<FinMoney, FinMoneyError> {
+        let amount = self
+            .amount
+            .checked_sub(d)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        Ok(FinMoney::new(amount, self.currency))
+    }
+
+    /// Multiplies this `FinMoney` by a `Decimal`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn checked_mul_decimal(&self, d: Decimal) -> Result<FinMoney, FinMoneyError> {
+        let amount = self
+            .amount
+            .checked_mul(d)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        Ok(FinMoney::new(amount, self.currency))
+    }
+
+    /// Divides this `FinMoney` by a `Decimal`, rounding according to the strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::DivisionByZero` if the divisor is zero.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn checked_div(
+        &self,
+        d: Decimal,
+        round_strategy: FinMoneyRoundingStrategy,
+    ) -> Result<FinMoney, FinMoneyError> {
+        if d.is_zero() {
+            return Err(FinMoneyError::DivisionByZero);
+        }
+        let raw = self
+            .amount
+            .checked_div(d)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        let rounded = self.round_result(raw, round_strategy);
+        Ok(FinMoney::new(rounded, self.currency))
+    }
+
+    /// Returns the remainder of dividing this `FinMoney` by a `Decimal` (e.g. a tick size).
+    ///
+    /// The result has the sign of `self`, like the `%` operator on `Decimal`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::DivisionByZero` if the divisor is zero.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn checked_rem(&self, d: Decimal) -> Result<FinMoney, FinMoneyError> {
+        if d.is_zero() {
+            return Err(FinMoneyError::DivisionByZero);
+        }
+        let amount = self
+            .amount
+            .checked_rem(d)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        Ok(FinMoney::new(amount, self.currency))
+    }
+
+    /// Returns the square root of the amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidAmount` if the amount is negative.
+    pub fn checked_sqrt(&self) -> Result<FinMoney, FinMoneyError> {
+        let root = self.amount.sqrt().ok_or_else(|| {
+            FinMoneyError::InvalidAmount(format!("square root of negative amount {}", self.amount))
+        })?;
+        Ok(FinMoney::new(root, self.currency))
+    }
+
+    // -- In-place Arithmetic --
+
+    /// Adds another `FinMoney` value in place, ensuring the same currency.
+    ///
+    /// On error, `self` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` if the currencies don't match.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinMoney, FinMoneyCurrency};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let mut position = FinMoney::new(dec!(10), FinMoneyCurrency::USD);
+    /// position.try_add_assign(FinMoney::new(dec!(2.50), FinMoneyCurrency::USD))?;
+    /// assert_eq!(position.get_amount(), dec!(12.50));
+    ///
+    /// let wrong_fill = FinMoney::new(dec!(1), FinMoneyCurrency::EUR);
+    /// assert!(position.try_add_assign(wrong_fill).is_err());
+    /// assert_eq!(position.get_amount(), dec!(12.50));
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn try_add_assign(&mut self, other: FinMoney) -> Result<(), FinMoneyError> {
+        *self = self.checked_add(other)?;
+        Ok(())
+    }
+
+    /// Subtracts another `FinMoney` value in place, ensuring the same currency.
+    ///
+    /// On error, `self` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` if the currencies don't match.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn try_sub_assign(&mut self, other: FinMoney) -> Result<(), FinMoneyError> {
+        *self = self.checked_sub(other)?;
+        Ok(())
+    }
+
+    /// Multiplies by a `Decimal` in place.
+    ///
+    /// On error, `self` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn try_mul_assign(&mut self, d: Decimal) -> Result<(), FinMoneyError> {
+        *self = self.checked_mul_decimal(d)?;
+        Ok(())
+    }
+
+    // -- Saturating Arithmetic --
+
+    /// Adds another `FinMoney` value, clamping a result that overflows
+    /// [`min_value`](Self::min_value)..=[`max_value`](Self::max_value) of the currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` if the currencies don't match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinMoney, FinMoneyCurrency};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let usd = FinMoneyCurrency::USD;
+    /// let max = FinMoney::max_value(usd);
+    /// let sum = max.saturating_add(FinMoney::new(dec!(1), usd))?;
+    /// assert_eq!(sum, max);
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn saturating_add(&self, other: FinMoney) -> Result<FinMoney, FinMoneyError> {
+        self.assert_same_currency(other)?;
+        let amount = self.amount.checked_add(other.amount);
+        Ok(self.saturate(amount, other.amount.is_sign_negative()))
+    }
+
+    /// Subtracts another `FinMoney` value, clamping a result that overflows
+    /// [`min_value`](Self::min_value)..=[`max_value`](Self::max_value) of the currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` if the currencies don't match.
+    pub fn saturating_sub(&self, other: FinMoney) -> Result<FinMoney, FinMoneyError> {
+        self.assert_same_currency(other)?;
+        let amount = self.amount.checked_sub(other.amount);
+        Ok(self.saturate(amount, other.amount.is_sign_positive()))
+    }
+
+    /// Multiplies by a `Decimal`, clamping a result that overflows
+    /// [`min_value`](Self::min_value)..=[`max_value`](Self::max_value) of the currency.
+    pub fn saturating_mul_decimal(&self, d: Decimal) -> FinMoney {
+        let amount = self.amount.checked_mul(d);
+        self.saturate(
+            amount,
+            self.amount.is_sign_negative() != d.is_sign_negative(),
+        )
+    }
+
+    /// Clamps the result of an operation on `self` into the currency range; `None` means
+    /// overflow in the given direction.
+    ///
+    /// Only a result that the operation moved past a bound is clamped, and never to a value
+    /// behind `self`: an amount that was already out of range is kept rather than pulled back
+    /// into range against the direction of the operation.
+    pub(crate) fn saturate(&self, amount: Option<Decimal>, overflow_negative: bool) -> FinMoney {
+        let max = Self::max_value(self.currency).amount;
+        let min = -max;
+        let amount = match amount {
+            Some(amount) if amount > max && amount > self.amount => max.max(self.amount),
+            Some(amount) if amount < min && amount < self.amount => min.min(self.amount),
+            Some(amount) => amount,
+            None if overflow_negative => min.min(self.amount),
+            None => max.max(self.amount),
+        };
+        FinMoney::new(amount, self.currency)
+    }
+
     // -- Arithmetic Operations --
 
     /// Adds another `FinMoney` value to this one, ensuring the same currency.
     ///
+    /// Equivalent to [`checked_add`](Self::checked_add).
+    ///
     /// # Errors
     ///
     /// Returns `FinMoneyError::CurrencyMismatch` if the currencies don't match.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
     pub fn plus_money(&self, other: FinMoney) -> Result<FinMoney, FinMoneyError> {
-        self.assert_same_currency(other)?;
-        Ok(FinMoney::new(self.amount + other.amount, self.currency))
+        self.checked_add(other)
     }
 
     /// Adds a `Decimal` amount to this `FinMoney`.
+    ///
+    /// Saturates at [`min_value`](Self::min_value)..=[`max_value`](Self::max_value) of the
+    /// currency if the result is out of range.
+    #[deprecated(
+        since = "2.0.0",
+        note = "use `checked_add_decimal`, which reports overflow"
+    )]
     pub fn plus_decimal(&self, d: Decimal) -> FinMoney {
-        FinMoney::new(self.amount + d, self.currency)
+        self.saturate(self.amount.checked_add(d), d.is_sign_negative())
     }
 
     /// Subtracts another `FinMoney` value from this one, ensuring the same currency.
     ///
+    /// Equivalent to [`checked_sub`](Self::checked_sub).
+    ///
     /// # Errors
     ///
     /// Returns `FinMoneyError::CurrencyMismatch` if the currencies don't match.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
     pub fn minus_money(&self, other: FinMoney) -> Result<FinMoney, FinMoneyError> {
-        self.assert_same_currency(other)?;
-        Ok(FinMoney::new(self.amount - other.amount, self.currency))
+        self.checked_sub(other)
     }
 
     /// Subtracts a `Decimal` amount from this `FinMoney`.
+    ///
+    /// Saturates at [`min_value`](Self::min_value)..=[`max_value`](Self::max_value) of the
+    /// currency if the result is out of range.
+    #[deprecated(
+        since = "2.0.0",
+        note = "use `checked_sub_decimal`, which reports overflow"
+    )]
     pub fn minus_decimal(&self, d: Decimal) -> FinMoney {
-        FinMoney::new(self.amount - d, self.currency)
+        self.saturate(self.amount.checked_sub(d), d.is_sign_positive())
     }
 
     /// Multiplies this `FinMoney` by another `FinMoney`, ensuring the same currency.
@@ -201,14 +517,21 @@ impl FinMoney {
     /// # Errors
     ///
     /// Returns `FinMoneyError::CurrencyMismatch` if the currencies don't match.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
     pub fn multiplied_by_money(&self, other: FinMoney) -> Result<FinMoney, FinMoneyError> {
         self.assert_same_currency(other)?;
-        Ok(FinMoney::new(self.amount * other.amount, self.currency))
+        self.checked_mul_decimal(other.amount)
     }
 
     /// Multiplies this `FinMoney` by a `Decimal`.
+    ///
+    /// Equivalent to [`saturating_mul_decimal`](Self::saturating_mul_decimal).
+    #[deprecated(
+        since = "2.0.0",
+        note = "use `checked_mul_decimal`, which reports overflow"
+    )]
     pub fn multiplied_by_decimal(&self, d: Decimal) -> FinMoney {
-        FinMoney::new(self.amount * d, self.currency)
+        self.saturating_mul_decimal(d)
     }
 
     /// Divides this `FinMoney` by another `FinMoney`, rounding according to the strategy.
@@ -217,36 +540,30 @@ impl FinMoney {
     ///
     /// Returns `FinMoneyError::CurrencyMismatch` if the currencies don't match.
     /// Returns `FinMoneyError::DivisionByZero` if the divisor is zero.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
     pub fn divided_by_money(
         &self,
         other: FinMoney,
         round_strategy: FinMoneyRoundingStrategy,
     ) -> Result<FinMoney, FinMoneyError> {
         self.assert_same_currency(other)?;
-        if other.amount.is_zero() {
-            return Err(FinMoneyError::DivisionByZero);
-        }
-        let raw = self.amount / other.amount;
-        let rounded = self.round_result(raw, round_strategy);
-        Ok(FinMoney::new(rounded, self.currency))
+        self.checked_div(other.amount, round_strategy)
     }
 
     /// Divides this `FinMoney` by a `Decimal`, rounding according to the strategy.
     ///
+    /// Equivalent to [`checked_div`](Self::checked_div).
+    ///
     /// # Errors
     ///
     /// Returns `FinMoneyError::DivisionByZero` if the divisor is zero.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
     pub fn divided_by_decimal(
         &self,
         d: Decimal,
         round_strategy: FinMoneyRoundingStrategy,
     ) -> Result<FinMoney, FinMoneyError> {
-        if d.is_zero() {
-            return Err(FinMoneyError::DivisionByZero);
-        }
-        let raw = self.amount / d;
-        let rounded = self.round_result(raw, round_strategy);
-        Ok(FinMoney::new(rounded, self.currency))
+        self.checked_div(d, round_strategy)
     }
 
     // -- Comparison Operations --
@@ -451,6 +768,7 @@ impl FinMoney {
     /// # Panics
     ///
     /// Panics if the amount is negative (square root of negative number).
+    #[deprecated(since = "2.0.0", note = "use `checked_sqrt`, which cannot panic")]
     pub fn sqrt(&self) -> FinMoney {
         FinMoney::new(self.amount.sqrt().unwrap(), self.currency)
     }
@@ -479,14 +797,10 @@ impl FinMoney {
     ///
     /// Returns `FinMoneyError::CurrencyMismatch` if currencies don't match.
     /// Returns `FinMoneyError::DivisionByZero` if initial amount is zero.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
     pub fn percent_change_from(&self, initial: FinMoney) -> Result<Decimal, FinMoneyError> {
         self.assert_same_currency(initial)?;
-
-        if initial.amount.is_zero() {
-            return Err(FinMoneyError::DivisionByZero);
-        }
-
-        Ok(((self.amount - initial.amount) * dec!(100)) / initial.amount)
+        Self::checked_percent_of(self.amount.checked_sub(initial.amount), initial.amount)
     }
 
     /// Calculates the negative percentage change from the initial FinMoney to this FinMoney value.
@@ -496,17 +810,13 @@ impl FinMoney {
     ///
     /// Returns `FinMoneyError::CurrencyMismatch` if currencies don't match.
     /// Returns `FinMoneyError::DivisionByZero` if initial amount is zero.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
     pub fn negative_percent_change_from(
         &self,
         initial: FinMoney,
     ) -> Result<Decimal, FinMoneyError> {
         self.assert_same_currency(initial)?;
-
-        if initial.amount.is_zero() {
-            return Err(FinMoneyError::DivisionByZero);
-        }
-
-        Ok(((initial.amount - self.amount) * dec!(100)) / initial.amount)
+        Self::checked_percent_of(initial.amount.checked_sub(self.amount), initial.amount)
     }
 
     /// Static method to calculate percentage change between two FinMoney values.
@@ -551,6 +861,37 @@ impl FinMoney {
         let rounded = self.amount.round_dp(dp);
         FinMoney::new(rounded, self.currency)
     }
+
+    // -- Formatting --
+
+    /// Formats the amount followed by the currency symbol from `registry`, or by the code if
+    /// the registry has no symbol for the currency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{
+    ///     FinCurrencyKind, FinCurrencyMetadata, FinMoney, FinMoneyCurrencyRegistry, iso4217,
+    /// };
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let registry = FinMoneyCurrencyRegistry::with_currencies([iso4217::EUR])?;
+    /// let price = FinMoney::new(dec!(10.50), iso4217::EUR);
+    /// assert_eq!(price.to_symbol_string(&registry), "10.50 EUR");
+    ///
+    /// registry.set_metadata(
+    ///     iso4217::EUR,
+    ///     FinCurrencyMetadata::new(FinCurrencyKind::Fiat).with_symbol("€"),
+    /// )?;
+    /// assert_eq!(price.to_symbol_string(&registry), "10.50 €");
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn to_symbol_string(&self, registry: &FinMoneyCurrencyRegistry) -> String {
+        match registry.get_symbol(&self.currency) {
+            Some(symbol) => format!("{} {}", self.amount, symbol),
+            None => self.to_string(),
+        }
+    }
 }
 // -- Tick Operations --
 
@@ -565,7 +906,8 @@ impl FinMoney {
     ///
     /// # Errors
     ///
-    /// Returns `MoneyError::InvalidTick` if tick is zero or negative.
+    /// Returns `FinMoneyError::InvalidTick` if tick is zero or negative.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
     ///
     /// # Examples
     ///
@@ -596,9 +938,14 @@ impl FinMoney {
             return Ok(FinMoney::new(amt, self.currency));
         }
         // General path: k = amount / tick → round k to integer → multiply back
-        let k = self.amount / tick;
+        let k = self
+            .amount
+            .checked_div(tick)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
         let k_rounded = k.round_dp_with_strategy(0, s);
-        let amt = k_rounded * tick;
+        let amt = k_rounded
+            .checked_mul(tick)
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
         Ok(FinMoney::new(amt, self.currency))
     }
 
@@ -654,9 +1001,10 @@ impl FinMoney {
             return amt == self.amount;
         }
 
-        // General case: check if amount/tick is an integer
-        let k = self.amount / tick;
-        k.fract().is_zero()
+        // General case: check if the remainder of amount/tick is zero
+        self.amount
+            .checked_rem(tick)
+            .is_some_and(|remainder| remainder.is_zero())
     }
 
     /// Helper function: if tick == 10^-dp (e.g., 0.001 → dp=3), return dp.
@@ -679,7 +1027,7 @@ impl Add for FinMoney {
     type Output = Result<FinMoney, FinMoneyError>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        self.plus_money(rhs)
+        self.checked_add(rhs)
     }
 }
 
@@ -687,31 +1035,117 @@ impl Sub for FinMoney {
     type Output = Result<FinMoney, FinMoneyError>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        self.minus_money(rhs)
+        self.checked_sub(rhs)
     }
 }
 
 impl Mul<Decimal> for FinMoney {
-    type Output = FinMoney;
+    type Output = Result<FinMoney, FinMoneyError>;
 
     fn mul(self, rhs: Decimal) -> Self::Output {
-        self.multiplied_by_decimal(rhs)
+        self.checked_mul_decimal(rhs)
     }
 }
 
+impl Mul<FinMoney> for Decimal {
+    type Output = Result<FinMoney, FinMoneyError>;
+
+    fn mul(self, rhs: FinMoney) -> Self::Output {
+        rhs.checked_mul_decimal(self)
+    }
+}
+
+/// Divides using the default rounding strategy (`MidpointNearestEven`) to the currency's
+/// precision, like [`FinMoney::divided_by_decimal`].
+impl Div<Decimal> for FinMoney {
+    type Output = Result<FinMoney, FinMoneyError>;
+
+    fn div(self, rhs: Decimal) -> Self::Output {
+        self.checked_div(rhs, FinMoneyRoundingStrategy::default())
+    }
+}
+
+impl Rem<Decimal> for FinMoney {
+    type Output = Result<FinMoney, FinMoneyError>;
+
+    fn rem(self, rhs: Decimal) -> Self::Output {
+        self.checked_rem(rhs)
+    }
+}
+
+impl Neg for FinMoney {
+    type Output = FinMoney;
+
+    fn neg(self) -> Self::Output {
+        self.negated()
+    }
+}
+
+impl Neg for &FinMoney {
+    type Output = FinMoney;
+
+    fn neg(self) -> Self::Output {
+        self.negated()
+    }
+}
+
+/// Implements the reference variants of a binary operator by copying the operands.
+macro_rules! forward_ref_binop {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl $imp<$u> for &$t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, rhs: $u) -> Self::Output {
+                $imp::$method(*self, rhs)
+            }
+        }
+
+        impl $imp<&$u> for $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, rhs: &$u) -> Self::Output {
+                $imp::$method(self, *rhs)
+            }
+        }
+
+        impl $imp<&$u> for &$t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, rhs: &$u) -> Self::Output {
+                $imp::$method(*self, *rhs)
+            }
+        }
+    };
+}
+
+forward_ref_binop!(impl Add, add for FinMoney, FinMoney);
+forward_ref_binop!(impl Sub, sub for FinMoney, FinMoney);
+forward_ref_binop!(impl Mul, mul for FinMoney, Decimal);
+forward_ref_binop!(impl Mul, mul for Decimal, FinMoney);
+forward_ref_binop!(impl Div, div for FinMoney, Decimal);
+forward_ref_binop!(impl Rem, rem for FinMoney, Decimal);
+
+/// Panics on currency mismatch or overflow; prefer [`FinMoney::try_add_assign`].
+///
+/// Not available with the `no-panic-ops` feature.
+#[cfg(not(feature = "no-panic-ops"))]
 impl AddAssign for FinMoney {
     fn add_assign(&mut self, rhs: Self) {
-        *self = self
-            .plus_money(rhs)
-            .expect("Currency mismatch in AddAssign");
+        if let Err(e) = self.try_add_assign(rhs) {
+            panic!("AddAssign failed: {}", e);
+        }
     }
 }
 
+/// Panics on currency mismatch or overflow; prefer [`FinMoney::try_sub_assign`].
+///
+/// Not available with the `no-panic-ops` feature.
+#[cfg(not(feature = "no-panic-ops"))]
 impl SubAssign for FinMoney {
     fn sub_assign(&mut self, rhs: Self) {
-        *self = self
-            .minus_money(rhs)
-            .expect("Currency mismatch in SubAssign");
+        if let Err(e) = self.try_sub_assign(rhs) {
+            panic!("SubAssign failed: {}", e);
+        }
     }
 }
 
@@ -0,0 +1,369 @@
+//! Currency pairs and market quoting conventions.
+
+use crate::{FinMoneyCurrency, FinMoneyCurrencyRegistry, FinMoneyError, FinMoneyRoundingStrategy};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// An ordered pair of currencies: the base is priced in the quote.
+///
+/// Deserialization applies the same checks as [`new`](Self::new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCurrencyPair"))]
+pub struct FinCurrencyPair {
+    base: FinMoneyCurrency,
+    quote: FinMoneyCurrency,
+}
+
+impl FinCurrencyPair {
+    /// Creates a new pair.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidCurrencyPair` if `base` and `quote` are the same currency.
+    pub fn new(base: FinMoneyCurrency, quote: FinMoneyCurrency) -> Result<Self, FinMoneyError> {
+        if base.is_same_currency(&quote) {
+            return Err(FinMoneyError::InvalidCurrencyPair(format!(
+                "{}/{}",
+                base.get_code(),
+                quote.get_code()
+            )));
+        }
+        Ok(Self { base, quote })
+    }
+
+    /// Parses a pair such as `"EUR/USD"`, `"EURUSD"` or `"BTC-USDT"`, resolving codes against
+    /// `registry`.
+    ///
+    /// Codes are matched case-insensitively, as by
+    /// [`FinMoneyCurrencyRegistry::get_by_code_ignore_case`]. Without a `/`, `-` or `_`
+    /// separator, the input is split at the only position where both halves are registered
+    /// codes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidCurrencyCode` if a separated code is empty, longer than 16
+    /// bytes, or contains whitespace or non-ASCII characters.
+    /// Returns `FinMoneyError::UnknownCurrency` if a separated code is well-formed but not
+    /// registered.
+    /// Returns `FinMoneyError::InvalidCurrencyPair` if an unseparated input cannot be split
+    /// unambiguously, or both codes name the same currency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinCurrencyPair, FinMoneyCurrency, FinMoneyCurrencyRegistry};
+    ///
+    /// let registry = FinMoneyCurrencyRegistry::new();
+    /// let usdt = FinMoneyCurrency::new(10, "USDT", None, 6)?;
+    /// registry.register_all([FinMoneyCurrency::BTC, FinMoneyCurrency::USD, usdt])?;
+    ///
+    /// let pair = FinCurrencyPair::parse("BTCUSDT", &registry)?;
+    /// assert_eq!(pair.get_base(), FinMoneyCurrency::BTC);
+    /// assert_eq!(pair.get_quote(), usdt);
+    /// assert_eq!(pair.to_string(), "BTC/USDT");
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn parse(input: &str, registry: &FinMoneyCurrencyRegistry) -> Result<Self, FinMoneyError> {
+        Self::parse_with(input, |code| registry.get_by_code_ignore_case(code))
+    }
+
+    fn parse_with(
+        input: &str,
+        resolve: impl Fn(&str) -> Option<FinMoneyCurrency>,
+    ) -> Result<Self, FinMoneyError> {
+        let trimmed = input.trim();
+        if let Some((base, quote)) = trimmed.split_once(['/', '-', '_']) {
+            let lookup = |code: &str| {
+                let well_formed = !code.is_empty()
+                    && code.len() <= 16
+                    && code.bytes().all(|b| b.is_ascii_graphic());
+                if !well_formed {
+                    return Err(FinMoneyError::InvalidCurrencyCode(code.to_string()));
+                }
+                resolve(code).ok_or_else(|| FinMoneyError::UnknownCurrency(code.to_string()))
+            };
+            return Self::new(lookup(base)?, lookup(quote)?);
+        }
+
+        let mut splits = (1..trimmed.len())
+            .filter(|&at| trimmed.is_char_boundary(at))
+            .filter_map(|at| {
+                let (base, quote) = trimmed.split_at(at);
+                Some((resolve(base)?, resolve(quote)?))
+            });
+        match (splits.next(), splits.next()) {
+            (Some((base, quote)), None) => Self::new(base, quote),
+            _ => Err(FinMoneyError::InvalidCurrencyPair(input.to_string())),
+        }
+    }
+
+    /// Returns the currency being priced.
+    pub fn get_base(&self) -> FinMoneyCurrency {
+        self.base
+    }
+
+    /// Returns the currency the price is expressed in.
+    pub fn get_quote(&self) -> FinMoneyCurrency {
+        self.quote
+    }
+
+    /// Returns the pair with base and quote swapped.
+    pub fn inverse(&self) -> FinCurrencyPair {
+        FinCurrencyPair {
+            base: self.quote,
+            quote: self.base,
+        }
+    }
+
+    /// Returns `true` if both pairs hold the same currencies, in either order.
+    pub fn is_same_pair(&self, other: &FinCurrencyPair) -> bool {
+        (self.base.is_same_currency(&other.base) && self.quote.is_same_currency(&other.quote))
+            || (self.base.is_same_currency(&other.quote)
+                && self.quote.is_same_currency(&other.base))
+    }
+
+    fn key(&self) -> (i32, i32) {
+        (self.base.get_id(), self.quote.get_id())
+    }
+}
+
+/// The serialized form of [`FinCurrencyPair`], validated by [`FinCurrencyPair::new`] on
+/// deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawCurrencyPair {
+    base: FinMoneyCurrency,
+    quote: FinMoneyCurrency,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCurrencyPair> for FinCurrencyPair {
+    type Error = FinMoneyError;
+
+    fn try_from(raw: RawCurrencyPair) -> Result<Self, Self::Error> {
+        FinCurrencyPair::new(raw.base, raw.quote)
+    }
+}
+
+impl FromStr for FinCurrencyPair {
+    type Err = FinMoneyError;
+
+    /// Parses a pair of ISO 4217 codes; see [`FinCurrencyPair::parse`] for other currencies.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, FinMoneyCurrency::from_iso_alpha)
+    }
+}
+
+impl fmt::Display for FinCurrencyPair {
+    /// Formats the pair as `BASE/QUOTE`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.base.get_code(), self.quote.get_code())
+    }
+}
+
+/// How the rate of a currency pair is quoted.
+///
+/// Deserialization applies the same checks as [`new`](Self::new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawPairConvention"))]
+pub struct FinPairConvention {
+    quote_precision: u32,
+    pip_size: Decimal,
+}
+
+impl FinPairConvention {
+    /// Creates a convention quoting rates with `quote_precision` decimal places and pips of
+    /// `pip_size` (e.g. `0.0001` for EUR/USD, `0.01` for USD/JPY).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidPrecision` if `quote_precision` > 28.
+    /// Returns `FinMoneyError::InvalidTick` if `pip_size` is zero or negative.
+    pub fn new(quote_precision: u32, pip_size: Decimal) -> Result<Self, FinMoneyError> {
+        if quote_precision > 28 {
+            return Err(FinMoneyError::InvalidPrecision(quote_precision));
+        }
+        if pip_size <= Decimal::ZERO {
+            return Err(FinMoneyError::InvalidTick);
+        }
+        Ok(Self {
+            quote_precision,
+            pip_size,
+        })
+    }
+
+    /// Returns the number of decimal places rates are quoted with.
+    pub fn get_quote_precision(&self) -> u32 {
+        self.quote_precision
+    }
+
+    /// Returns the size of one pip.
+    pub fn get_pip_size(&self) -> Decimal {
+        self.pip_size
+    }
+
+    /// Rounds `rate` to the quoted number of decimal places with `strategy`.
+    pub fn round_rate(&self, rate: Decimal, strategy: FinMoneyRoundingStrategy) -> Decimal {
+        rate.round_dp_with_strategy(self.quote_precision, strategy.to_decimal_strategy())
+    }
+
+    /// Converts a rate difference into pips.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn to_pips(&self, difference: Decimal) -> Result<Decimal, FinMoneyError> {
+        difference
+            .checked_div(self.pip_size)
+            .ok_or(FinMoneyError::ArithmeticOverflow)
+    }
+
+    /// Converts a number of pips into a rate difference.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn from_pips(&self, pips: Decimal) -> Result<Decimal, FinMoneyError> {
+        pips.checked_mul(self.pip_size)
+            .ok_or(FinMoneyError::ArithmeticOverflow)
+    }
+}
+
+/// The serialized form of [`FinPairConvention`], validated by [`FinPairConvention::new`] on
+/// deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawPairConvention {
+    quote_precision: u32,
+    pip_size: Decimal,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawPairConvention> for FinPairConvention {
+    type Error = FinMoneyError;
+
+    fn try_from(raw: RawPairConvention) -> Result<Self, Self::Error> {
+        FinPairConvention::new(raw.quote_precision, raw.pip_size)
+    }
+}
+
+impl Default for FinPairConvention {
+    /// Five decimal places with a pip of `0.0001`, as for most major pairs.
+    fn default() -> Self {
+        Self {
+            quote_precision: 5,
+            pip_size: dec!(0.0001),
+        }
+    }
+}
+
+/// A table of market conventions: which currency of a pair is the base, and how its rate is
+/// quoted.
+///
+/// Currencies earlier in the priority list are the base of pairs with currencies later in it or
+/// not in it at all. Pairs of two unranked currencies keep the order they are given in.
+#[derive(Debug, Clone, Default)]
+pub struct FinPairConventions {
+    priority: Vec<i32>,
+    conventions: HashMap<(i32, i32), FinPairConvention>,
+    default: FinPairConvention,
+}
+
+impl FinPairConventions {
+    /// Creates an empty table using [`FinPairConvention::default`] for every pair.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the interbank FX conventions for the ISO 4217 majors.
+    ///
+    /// The base priority is EUR, GBP, AUD, NZD, USD, CAD, CHF, then JPY. JPY-quoted pairs use
+    /// three decimal places and a pip of `0.01`; all others use the default convention.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinCurrencyPair, FinPairConventions};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let conventions = FinPairConventions::market();
+    /// let pair: FinCurrencyPair = "JPY/USD".parse()?;
+    ///
+    /// let market = conventions.normalize(pair);
+    /// assert_eq!(market.to_string(), "USD/JPY");
+    /// assert_eq!(conventions.get_convention(market).get_pip_size(), dec!(0.01));
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn market() -> Self {
+        use crate::iso4217::{AUD, CAD, CHF, EUR, GBP, JPY, NZD, USD};
+
+        let majors = [EUR, GBP, AUD, NZD, USD, CAD, CHF, JPY];
+        let mut table = Self::new().with_priority(&majors);
+        let yen = FinPairConvention {
+            quote_precision: 3,
+            pip_size: dec!(0.01),
+        };
+        for base in &majors[..majors.len() - 1] {
+            table = table.with_convention(
+                FinCurrencyPair {
+                    base: *base,
+                    quote: JPY,
+                },
+                yen,
+            );
+        }
+        table
+    }
+
+    /// Sets the base priority, most senior currency first.
+    pub fn with_priority(mut self, currencies: &[FinMoneyCurrency]) -> Self {
+        self.priority = currencies.iter().map(|c| c.get_id()).collect();
+        self
+    }
+
+    /// Sets the convention of `pair`, which also applies to its inverse.
+    pub fn with_convention(mut self, pair: FinCurrencyPair, convention: FinPairConvention) -> Self {
+        self.conventions.insert(pair.key(), convention);
+        self
+    }
+
+    /// Sets the convention used for pairs without one of their own.
+    pub fn with_default(mut self, convention: FinPairConvention) -> Self {
+        self.default = convention;
+        self
+    }
+
+    /// Returns `pair` oriented the way the market quotes it.
+    pub fn normalize(&self, pair: FinCurrencyPair) -> FinCurrencyPair {
+        let rank = |currency: FinMoneyCurrency| {
+            self.priority
+                .iter()
+                .position(|id| *id == currency.get_id())
+                .unwrap_or(usize::MAX)
+        };
+        if rank(pair.quote) < rank(pair.base) {
+            pair.inverse()
+        } else {
+            pair
+        }
+    }
+
+    /// Returns `true` if the market quotes `pair` in the given orientation.
+    pub fn is_market_order(&self, pair: FinCurrencyPair) -> bool {
+        self.normalize(pair) == pair
+    }
+
+    /// Returns the convention of `pair` in either orientation.
+    pub fn get_convention(&self, pair: FinCurrencyPair) -> FinPairConvention {
+        self.conventions
+            .get(&pair.key())
+            .or_else(|| self.conventions.get(&pair.inverse().key()))
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
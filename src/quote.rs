@@ -0,0 +1,230 @@
+//! Two-sided (bid/ask) quotes and side-aware conversion.
+
+use crate::{FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
+use rust_decimal::Decimal;
+use std::fmt;
+
+/// A two-sided price of a base currency in a quote currency.
+///
+/// The house buys the base currency at the `bid` and sells it at the `ask`. Conversions work in
+/// both directions and always round in the house's favor: amounts the counterparty pays are
+/// rounded up, amounts it receives down, so a negative amount (a refund or reversal) is rounded
+/// toward zero when paid and away from zero when received.
+///
+/// Deserialization applies the same checks as [`new`](Self::new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawQuote"))]
+pub struct FinQuote {
+    base: FinMoneyCurrency,
+    quote: FinMoneyCurrency,
+    bid: Decimal,
+    ask: Decimal,
+}
+
+impl FinQuote {
+    /// Creates a new quote for `base` priced in `quote`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidCurrencyPair` if `base` and `quote` are the same currency.
+    /// Returns `FinMoneyError::InvalidExchangeRate` if `bid` or `ask` is zero or negative.
+    /// Returns `FinMoneyError::CrossedQuote` if `bid` is greater than `ask`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinMoney, FinMoneyCurrency, FinQuote};
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let eur_usd = FinQuote::new(
+    ///     FinMoneyCurrency::EUR,
+    ///     FinMoneyCurrency::USD,
+    ///     dec!(1.0848),
+    ///     dec!(1.0852),
+    /// )?;
+    /// let euros = FinMoney::new(dec!(100.01), FinMoneyCurrency::EUR);
+    ///
+    /// // 108.530852 USD at the ask is rounded up, 108.490848 USD at the bid is rounded down
+    /// assert_eq!(eur_usd.convert_buy(&euros)?.get_amount(), dec!(108.54));
+    /// assert_eq!(eur_usd.convert_sell(&euros)?.get_amount(), dec!(108.49));
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn new(
+        base: FinMoneyCurrency,
+        quote: FinMoneyCurrency,
+        bid: Decimal,
+        ask: Decimal,
+    ) -> Result<Self, FinMoneyError> {
+        if base.is_same_currency(&quote) {
+            return Err(FinMoneyError::InvalidCurrencyPair(format!(
+                "{}/{}",
+                base.get_code(),
+                quote.get_code()
+            )));
+        }
+        for price in [bid, ask] {
+            if price <= Decimal::ZERO {
+                return Err(FinMoneyError::InvalidExchangeRate(price));
+            }
+        }
+        if bid > ask {
+            return Err(FinMoneyError::CrossedQuote { bid, ask });
+        }
+        Ok(Self {
+            base,
+            quote,
+            bid,
+            ask,
+        })
+    }
+
+    /// Returns the currency being priced.
+    pub fn get_base(&self) -> FinMoneyCurrency {
+        self.base
+    }
+
+    /// Returns the currency the prices are expressed in.
+    pub fn get_quote(&self) -> FinMoneyCurrency {
+        self.quote
+    }
+
+    /// Returns the price at which the house buys the base currency.
+    pub fn get_bid(&self) -> Decimal {
+        self.bid
+    }
+
+    /// Returns the price at which the house sells the base currency.
+    pub fn get_ask(&self) -> Decimal {
+        self.ask
+    }
+
+    /// Returns the mid price, halfway between bid and ask.
+    ///
+    /// Cannot overflow: `new` guarantees `0 < bid <= ask`.
+    pub fn mid(&self) -> Decimal {
+        self.bid + (self.ask - self.bid) / Decimal::TWO
+    }
+
+    /// Returns the absolute spread, `ask - bid`.
+    pub fn spread(&self) -> Decimal {
+        self.ask - self.bid
+    }
+
+    /// Returns the spread in basis points of the mid price.
+    ///
+    /// Cannot overflow: the mid price is positive and at least half the spread.
+    pub fn spread_bps(&self) -> Decimal {
+        self.spread() / self.mid() * Decimal::from(10_000)
+    }
+
+    /// Returns the spread as a number of `tick` increments.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidTick` if `tick` is zero or negative.
+    pub fn spread_ticks(&self, tick: Decimal) -> Result<Decimal, FinMoneyError> {
+        if tick <= Decimal::ZERO {
+            return Err(FinMoneyError::InvalidTick);
+        }
+        self.spread()
+            .checked_div(tick)
+            .ok_or(FinMoneyError::ArithmeticOverflow)
+    }
+
+    /// Converts at the ask, the side on which the counterparty buys the base currency.
+    ///
+    /// For `money` in the base currency, returns what the counterparty pays in the quote
+    /// currency to buy it. For `money` in the quote currency, returns the base currency the
+    /// counterparty receives for spending it. The result is rounded in the house's favor: a cost
+    /// up, an amount received down.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` if `money` is in neither currency of the quote.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn convert_buy(&self, money: &FinMoney) -> Result<FinMoney, FinMoneyError> {
+        self.convert_at(money, self.ask, true)
+    }
+
+    /// Converts at the bid, the side on which the counterparty sells the base currency.
+    ///
+    /// For `money` in the base currency, returns what the counterparty receives in the quote
+    /// currency for selling it. For `money` in the quote currency, returns the base currency the
+    /// counterparty must sell to receive it. The result is rounded in the house's favor: an
+    /// amount received down, a cost up.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyMismatch` if `money` is in neither currency of the quote.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn convert_sell(&self, money: &FinMoney) -> Result<FinMoney, FinMoneyError> {
+        self.convert_at(money, self.bid, false)
+    }
+
+    /// Converts `money` at `price` in either direction; `buy` selects which side is a cost.
+    fn convert_at(
+        &self,
+        money: &FinMoney,
+        price: Decimal,
+        buy: bool,
+    ) -> Result<FinMoney, FinMoneyError> {
+        let currency = money.get_currency();
+        let amount = money.get_amount();
+        // Base to quote prices what the counterparty buys (a cost) or sells (proceeds); quote
+        // to base swaps the roles.
+        let (converted, target, cost) = if currency.is_same_currency(&self.base) {
+            (amount.checked_mul(price), self.quote, buy)
+        } else if currency.is_same_currency(&self.quote) {
+            (amount.checked_div(price), self.base, !buy)
+        } else {
+            return Err(FinMoneyError::CurrencyMismatch {
+                expected: self.base.get_code().to_string(),
+                actual: currency.get_code().to_string(),
+                index: None,
+            });
+        };
+        let converted = converted.ok_or(FinMoneyError::ArithmeticOverflow)?;
+
+        let strategy = if cost {
+            FinMoneyRoundingStrategy::ToPositiveInfinity
+        } else {
+            FinMoneyRoundingStrategy::ToNegativeInfinity
+        };
+        Ok(FinMoney::new(converted, target)
+            .round_dp_with_strategy(u32::from(target.get_precision()), strategy))
+    }
+}
+
+/// The serialized form of [`FinQuote`], validated by [`FinQuote::new`] on deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawQuote {
+    base: FinMoneyCurrency,
+    quote: FinMoneyCurrency,
+    bid: Decimal,
+    ask: Decimal,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawQuote> for FinQuote {
+    type Error = FinMoneyError;
+
+    fn try_from(raw: RawQuote) -> Result<Self, Self::Error> {
+        FinQuote::new(raw.base, raw.quote, raw.bid, raw.ask)
+    }
+}
+
+impl fmt::Display for FinQuote {
+    /// Formats the quote as `BASE/QUOTE bid/ask`, e.g. `EUR/USD 1.0848/1.0852`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{} {}/{}",
+            self.base.get_code(),
+            self.quote.get_code(),
+            self.bid,
+            self.ask
+        )
+    }
+}
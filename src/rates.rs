@@ -0,0 +1,263 @@
+//! Exchange rate sources and cross-rate triangulation.
+
+use crate::{FinExchangeRate, FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+/// A source of exchange rates between currencies.
+pub trait RateProvider {
+    /// Returns the rate from `base` to `quote` together with the rates it was derived from.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::MissingExchangeRate` if no rate can be found or derived.
+    fn get_rate(
+        &self,
+        base: FinMoneyCurrency,
+        quote: FinMoneyCurrency,
+    ) -> Result<FinResolvedRate, FinMoneyError>;
+
+    /// Converts `money` into `quote`, rounding to its precision with `strategy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::MissingExchangeRate` if no rate can be found or derived.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    fn convert(
+        &self,
+        money: &FinMoney,
+        quote: FinMoneyCurrency,
+        strategy: FinMoneyRoundingStrategy,
+    ) -> Result<FinMoney, FinMoneyError> {
+        self.get_rate(money.get_currency(), quote)?
+            .get_rate()
+            .convert(money, strategy)
+    }
+}
+
+/// A rate returned by a [`RateProvider`], with the hops it was computed from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinResolvedRate {
+    rate: FinExchangeRate,
+    hops: Vec<FinExchangeRate>,
+}
+
+impl FinResolvedRate {
+    /// Returns the effective rate from the requested base to the requested quote.
+    pub fn get_rate(&self) -> FinExchangeRate {
+        self.rate
+    }
+
+    /// Returns the rates multiplied together, in conversion order.
+    ///
+    /// Inverted rates appear in the direction they were applied. Empty when base and quote are
+    /// the same currency.
+    pub fn get_hops(&self) -> &[FinExchangeRate] {
+        &self.hops
+    }
+
+    /// Returns the currencies visited, from base to quote.
+    pub fn get_path(&self) -> Vec<FinMoneyCurrency> {
+        let mut path = vec![self.rate.get_base()];
+        path.extend(self.hops.iter().map(|hop| hop.get_quote()));
+        path
+    }
+
+    /// Returns `true` if the rate needed no triangulation.
+    pub fn is_direct(&self) -> bool {
+        self.hops.len() <= 1
+    }
+}
+
+/// How [`FinRateTable`] derives rates between currencies that have no rate of their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FinTriangulation {
+    /// Only direct rates and their inverses.
+    #[default]
+    Direct,
+    /// Direct rates, otherwise a cross rate through the given currency.
+    Pivot(FinMoneyCurrency),
+    /// The path with the fewest hops; ties go to the path through lower currency ids.
+    ShortestPath,
+}
+
+/// An in-memory [`RateProvider`].
+///
+/// Stores direct rates; the inverse of every stored rate is derived on lookup, and missing
+/// pairs are triangulated according to the table's [`FinTriangulation`].
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinExchangeRate, FinRateTable, FinTriangulation, RateProvider, iso4217};
+/// use rust_decimal_macros::dec;
+///
+/// let mut table = FinRateTable::new(FinTriangulation::Pivot(iso4217::USD));
+/// table.insert(FinExchangeRate::new(iso4217::EUR, iso4217::USD, dec!(1.08))?);
+/// table.insert(FinExchangeRate::new(iso4217::USD, iso4217::JPY, dec!(150))?);
+///
+/// let eur_jpy = table.get_rate(iso4217::EUR, iso4217::JPY)?;
+/// assert_eq!(eur_jpy.get_rate().get_rate(), dec!(162));
+/// assert_eq!(eur_jpy.get_path(), vec![iso4217::EUR, iso4217::USD, iso4217::JPY]);
+/// # Ok::<(), finmoney::FinMoneyError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FinRateTable {
+    rates: HashMap<(i32, i32), FinExchangeRate>,
+    triangulation: FinTriangulation,
+}
+
+impl FinRateTable {
+    /// Creates an empty table using `triangulation` for missing pairs.
+    pub fn new(triangulation: FinTriangulation) -> Self {
+        Self {
+            rates: HashMap::new(),
+            triangulation,
+        }
+    }
+
+    /// Stores `rate`, replacing any rate previously stored for the same base and quote.
+    ///
+    /// Returns the replaced rate, if any.
+    pub fn insert(&mut self, rate: FinExchangeRate) -> Option<FinExchangeRate> {
+        let key = (rate.get_base().get_id(), rate.get_quote().get_id());
+        self.rates.insert(key, rate)
+    }
+
+    /// Removes the rate stored for `base` and `quote`.
+    pub fn remove(
+        &mut self,
+        base: FinMoneyCurrency,
+        quote: FinMoneyCurrency,
+    ) -> Option<FinExchangeRate> {
+        self.rates.remove(&(base.get_id(), quote.get_id()))
+    }
+
+    /// Returns the triangulation mode.
+    pub fn get_triangulation(&self) -> FinTriangulation {
+        self.triangulation
+    }
+
+    /// Changes the triangulation mode.
+    pub fn set_triangulation(&mut self, triangulation: FinTriangulation) {
+        self.triangulation = triangulation;
+    }
+
+    /// Returns the number of stored rates.
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    /// Returns `true` if no rates are stored.
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    // A stored rate or the inverse of one, at full precision.
+    fn hop(&self, base: FinMoneyCurrency, quote: FinMoneyCurrency) -> Option<FinExchangeRate> {
+        if let Some(rate) = self.rates.get(&(base.get_id(), quote.get_id())) {
+            return Some(*rate);
+        }
+        self.rates
+            .get(&(quote.get_id(), base.get_id()))
+            .and_then(|rate| {
+                rate.inverse(28, FinMoneyRoundingStrategy::MidpointNearestEven)
+                    .ok()
+            })
+    }
+
+    fn shortest_path(
+        &self,
+        base: FinMoneyCurrency,
+        quote: FinMoneyCurrency,
+    ) -> Option<Vec<FinMoneyCurrency>> {
+        let mut neighbours: BTreeMap<i32, BTreeSet<(i32, FinMoneyCurrency)>> = BTreeMap::new();
+        for rate in self.rates.values() {
+            let (b, q) = (rate.get_base(), rate.get_quote());
+            neighbours
+                .entry(b.get_id())
+                .or_default()
+                .insert((q.get_id(), q));
+            neighbours
+                .entry(q.get_id())
+                .or_default()
+                .insert((b.get_id(), b));
+        }
+
+        let mut previous: HashMap<i32, FinMoneyCurrency> = HashMap::new();
+        let mut queue = VecDeque::from([base]);
+        while let Some(current) = queue.pop_front() {
+            if current.is_same_currency(&quote) {
+                let mut path = vec![current];
+                while let Some(prev) = previous.get(&path[path.len() - 1].get_id()) {
+                    path.push(*prev);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for (id, next) in neighbours.get(&current.get_id()).into_iter().flatten() {
+                if *id != base.get_id() && !previous.contains_key(id) {
+                    previous.insert(*id, current);
+                    queue.push_back(*next);
+                }
+            }
+        }
+        None
+    }
+
+    fn resolve(
+        &self,
+        base: FinMoneyCurrency,
+        quote: FinMoneyCurrency,
+        path: &[FinMoneyCurrency],
+    ) -> Result<FinResolvedRate, FinMoneyError> {
+        let hops = path
+            .windows(2)
+            .map(|pair| self.hop(pair[0], pair[1]))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| missing(base, quote))?;
+        let rate = hops
+            .iter()
+            .try_fold(Decimal::ONE, |acc, hop| acc.checked_mul(hop.get_rate()))
+            .ok_or(FinMoneyError::ArithmeticOverflow)?;
+        Ok(FinResolvedRate {
+            rate: FinExchangeRate::new(base, quote, rate)?,
+            hops,
+        })
+    }
+}
+
+impl RateProvider for FinRateTable {
+    fn get_rate(
+        &self,
+        base: FinMoneyCurrency,
+        quote: FinMoneyCurrency,
+    ) -> Result<FinResolvedRate, FinMoneyError> {
+        if base.is_same_currency(&quote) {
+            return Ok(FinResolvedRate {
+                rate: FinExchangeRate::new(base, quote, Decimal::ONE)?,
+                hops: Vec::new(),
+            });
+        }
+        if self.hop(base, quote).is_some() {
+            return self.resolve(base, quote, &[base, quote]);
+        }
+        match self.triangulation {
+            FinTriangulation::Direct => Err(missing(base, quote)),
+            FinTriangulation::Pivot(pivot) => self.resolve(base, quote, &[base, pivot, quote]),
+            FinTriangulation::ShortestPath => {
+                let path = self
+                    .shortest_path(base, quote)
+                    .ok_or_else(|| missing(base, quote))?;
+                self.resolve(base, quote, &path)
+            }
+        }
+    }
+}
+
+fn missing(base: FinMoneyCurrency, quote: FinMoneyCurrency) -> FinMoneyError {
+    FinMoneyError::MissingExchangeRate {
+        base: base.get_code().to_string(),
+        quote: quote.get_code().to_string(),
+    }
+}
@@ -0,0 +1,478 @@
+//! Runtime registry of currency definitions.
+
+use crate::error::{FinMoneyError, Result};
+use crate::{
+    FinCurrencyKind, FinCurrencyLifecycle, FinCurrencyMetadata, FinDate, FinDenomination,
+    FinMoneyCurrency,
+};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{PoisonError, RwLock};
+
+/// An authoritative, thread-safe store of currency definitions.
+///
+/// `FinMoneyCurrency::is_same_currency` only compares ids, so two independently constructed
+/// currencies with the same id but different codes compare equal. The registry prevents this
+/// by rejecting registrations that conflict with an existing definition:
+///
+/// - the same id with a different code or precision, or
+/// - the same code with a different id.
+///
+/// Registering an identical definition again is a no-op. The registry uses interior
+/// mutability, so it can be shared between threads behind an `Arc`.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinMoneyCurrency, FinMoneyCurrencyRegistry, FinMoneyError};
+/// use std::sync::Arc;
+///
+/// let registry = Arc::new(FinMoneyCurrencyRegistry::new());
+/// registry.register(FinMoneyCurrency::USD)?;
+///
+/// let usd = registry.get_by_code("USD").unwrap();
+/// assert_eq!(registry.get_by_id(usd.get_id()), Some(usd));
+///
+/// // Same id, different code: rejected.
+/// let fake = FinMoneyCurrency::new(840, "EUR", None, 2)?;
+/// assert!(matches!(
+///     registry.register(fake),
+///     Err(FinMoneyError::CurrencyConflict { .. })
+/// ));
+/// # Ok::<(), FinMoneyError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct FinMoneyCurrencyRegistry {
+    inner: RwLock<RegistryInner>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct RegistryInner {
+    by_id: HashMap<i32, FinMoneyCurrency>,
+    id_by_code: HashMap<String, i32>,
+    lifecycles: HashMap<i32, FinCurrencyLifecycle>,
+    metadata: HashMap<i32, FinCurrencyMetadata>,
+    units: HashMap<i32, BTreeMap<String, Decimal>>,
+}
+
+impl RegistryInner {
+    fn insert(&mut self, currency: FinMoneyCurrency) -> Result<()> {
+        if let Some(existing) = self.by_id.get(&currency.get_id()) {
+            if existing.get_code() != currency.get_code()
+                || existing.get_precision() != currency.get_precision()
+            {
+                return Err(Self::conflict(existing, &currency));
+            }
+            return Ok(());
+        }
+
+        if let Some(existing) = self
+            .id_by_code
+            .get(currency.get_code())
+            .and_then(|id| self.by_id.get(id))
+        {
+            return Err(Self::conflict(existing, &currency));
+        }
+
+        self.id_by_code
+            .insert(currency.get_code().to_string(), currency.get_id());
+        self.by_id.insert(currency.get_id(), currency);
+        Ok(())
+    }
+
+    fn check_registered(&self, currency: &FinMoneyCurrency) -> Result<()> {
+        match self.by_id.get(&currency.get_id()) {
+            Some(existing)
+                if existing.get_code() == currency.get_code()
+                    && existing.get_precision() == currency.get_precision() =>
+            {
+                Ok(())
+            }
+            _ => Err(FinMoneyError::UnknownCurrency(
+                currency.get_code().to_string(),
+            )),
+        }
+    }
+
+    /// Returns the entry of `currency` in one of the per-currency maps.
+    ///
+    /// The maps are keyed by id, so the entry is only returned if exactly this currency
+    /// definition is registered; another currency that merely shares the id gets nothing.
+    fn entry<'a, T>(&self, map: &'a HashMap<i32, T>, currency: &FinMoneyCurrency) -> Option<&'a T> {
+        self.check_registered(currency).ok()?;
+        map.get(&currency.get_id())
+    }
+
+    fn conflict(existing: &FinMoneyCurrency, rejected: &FinMoneyCurrency) -> FinMoneyError {
+        FinMoneyError::CurrencyConflict {
+            existing: Self::describe(existing),
+            rejected: Self::describe(rejected),
+        }
+    }
+
+    fn describe(currency: &FinMoneyCurrency) -> String {
+        format!(
+            "{} (id {}, precision {})",
+            currency.get_code(),
+            currency.get_id(),
+            currency.get_precision()
+        )
+    }
+}
+
+impl FinMoneyCurrencyRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry pre-populated with the given currencies.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyConflict` if two of the currencies conflict.
+    pub fn with_currencies(currencies: impl IntoIterator<Item = FinMoneyCurrency>) -> Result<Self> {
+        let registry = Self::new();
+        registry.register_all(currencies)?;
+        Ok(registry)
+    }
+
+    /// Registers a currency definition.
+    ///
+    /// Registering a currency identical in id, code and precision to an existing one
+    /// succeeds without changing the registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyConflict` if the id is already registered with a
+    /// different code or precision, or if the code is already registered with a different id.
+    pub fn register(&self, currency: FinMoneyCurrency) -> Result<()> {
+        let mut inner = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        inner.insert(currency)
+    }
+
+    /// Registers a batch of currencies atomically.
+    ///
+    /// Every entry is checked against the registry and the rest of the batch. If any entry
+    /// conflicts, nothing is registered and the errors are returned together with the
+    /// position tag supplied for the entry.
+    #[cfg(feature = "config")]
+    pub(crate) fn register_batch<T: Copy>(
+        &self,
+        batch: impl IntoIterator<Item = (T, FinMoneyCurrency)>,
+    ) -> std::result::Result<usize, Vec<(T, FinMoneyError)>> {
+        let mut inner = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        let mut staged = inner.clone();
+        let before = staged.by_id.len();
+        let errors: Vec<_> = batch
+            .into_iter()
+            .filter_map(|(tag, currency)| staged.insert(currency).err().map(|e| (tag, e)))
+            .collect();
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let added = staged.by_id.len() - before;
+        *inner = staged;
+        Ok(added)
+    }
+
+    /// Registers several currencies, stopping at the first conflict.
+    ///
+    /// Currencies registered before the conflicting one remain in the registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::CurrencyConflict` on the first conflicting currency.
+    pub fn register_all(
+        &self,
+        currencies: impl IntoIterator<Item = FinMoneyCurrency>,
+    ) -> Result<()> {
+        currencies
+            .into_iter()
+            .try_for_each(|currency| self.register(currency))
+    }
+
+    /// Returns the currency registered under the given id.
+    pub fn get_by_id(&self, id: i32) -> Option<FinMoneyCurrency> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner.by_id.get(&id).copied()
+    }
+
+    /// Returns the currency registered under the given code.
+    pub fn get_by_code(&self, code: &str) -> Option<FinMoneyCurrency> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner
+            .id_by_code
+            .get(code)
+            .and_then(|id| inner.by_id.get(id))
+            .copied()
+    }
+
+    /// Returns the currency registered under the given code, ignoring ASCII case.
+    ///
+    /// An exact match is preferred. Otherwise, `None` is returned unless exactly one registered
+    /// code matches, so `"usdt"` is not resolved when both `USDT` and `UsdT` are registered.
+    pub fn get_by_code_ignore_case(&self, code: &str) -> Option<FinMoneyCurrency> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        let id = match inner.id_by_code.get(code) {
+            Some(id) => *id,
+            None => {
+                let mut matches = inner
+                    .id_by_code
+                    .iter()
+                    .filter(|(registered, _)| registered.eq_ignore_ascii_case(code))
+                    .map(|(_, id)| *id);
+                match (matches.next(), matches.next()) {
+                    (Some(id), None) => id,
+                    _ => return None,
+                }
+            }
+        };
+        inner.by_id.get(&id).copied()
+    }
+
+    /// Checks whether exactly this currency definition is registered.
+    pub fn contains(&self, currency: &FinMoneyCurrency) -> bool {
+        self.get_by_id(currency.get_id()).is_some_and(|existing| {
+            existing.get_code() == currency.get_code()
+                && existing.get_precision() == currency.get_precision()
+        })
+    }
+
+    /// Returns the number of registered currencies.
+    pub fn len(&self) -> usize {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner.by_id.len()
+    }
+
+    /// Checks if the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Attaches validity dates and an optional successor to a registered currency, replacing
+    /// any lifecycle it had.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::UnknownCurrency` if `currency` or the successor is not
+    /// registered.
+    /// Returns `FinMoneyError::InvalidDate` if the currency would stop being active before it
+    /// starts.
+    /// Returns `FinMoneyError::InvalidCurrencyPair` if the currency is its own successor, directly
+    /// or through the successors of its successor.
+    /// Returns `FinMoneyError::InvalidExchangeRate` if the redenomination factor is zero or
+    /// negative.
+    pub fn set_lifecycle(
+        &self,
+        currency: FinMoneyCurrency,
+        lifecycle: FinCurrencyLifecycle,
+    ) -> Result<()> {
+        let mut inner = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        inner.check_registered(&currency)?;
+        if let (Some(from), Some(to)) = (lifecycle.get_active_from(), lifecycle.get_active_to()) {
+            if to < from {
+                return Err(FinMoneyError::InvalidDate(format!(
+                    "{} is active from {} to {}",
+                    currency.get_code(),
+                    from,
+                    to
+                )));
+            }
+        }
+        if let Some((successor, factor)) = lifecycle.get_successor() {
+            if factor <= Decimal::ZERO {
+                return Err(FinMoneyError::InvalidExchangeRate(factor));
+            }
+            inner.check_registered(&successor)?;
+            // Every stored chain ends, so walking it terminates unless it leads back here.
+            let mut next = Some(successor);
+            while let Some(step) = next {
+                if step.is_same_currency(&currency) {
+                    return Err(FinMoneyError::InvalidCurrencyPair(format!(
+                        "{}/{}",
+                        currency.get_code(),
+                        successor.get_code()
+                    )));
+                }
+                next = inner
+                    .lifecycles
+                    .get(&step.get_id())
+                    .and_then(|lifecycle| lifecycle.get_successor())
+                    .map(|(successor, _)| successor);
+            }
+        }
+        inner.lifecycles.insert(currency.get_id(), lifecycle);
+        Ok(())
+    }
+
+    /// Returns the lifecycle of a registered currency, if one was set.
+    ///
+    /// A currency that shares the id of a registered one but differs in code or precision has
+    /// no lifecycle.
+    pub fn get_lifecycle(&self, currency: &FinMoneyCurrency) -> Option<FinCurrencyLifecycle> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner.entry(&inner.lifecycles, currency).copied()
+    }
+
+    /// Checks whether a currency was in use on `date`.
+    ///
+    /// Currencies without a lifecycle are considered always active.
+    pub fn is_active_on(&self, currency: &FinMoneyCurrency, date: FinDate) -> bool {
+        self.get_lifecycle(currency)
+            .is_none_or(|lifecycle| lifecycle.is_active_on(date))
+    }
+
+    /// Attaches classification and descriptive metadata to a registered currency, replacing any
+    /// metadata it had.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::UnknownCurrency` if `currency` is not registered.
+    pub fn set_metadata(
+        &self,
+        currency: FinMoneyCurrency,
+        metadata: FinCurrencyMetadata,
+    ) -> Result<()> {
+        let mut inner = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        inner.check_registered(&currency)?;
+        inner.metadata.insert(currency.get_id(), metadata);
+        Ok(())
+    }
+
+    /// Returns the metadata of a registered currency, if any was set.
+    ///
+    /// A currency that shares the id of a registered one but differs in code or precision has
+    /// no metadata.
+    pub fn get_metadata(&self, currency: &FinMoneyCurrency) -> Option<FinCurrencyMetadata> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner.entry(&inner.metadata, currency).cloned()
+    }
+
+    /// Returns the kind of a registered currency, if metadata was set.
+    pub fn get_kind(&self, currency: &FinMoneyCurrency) -> Option<FinCurrencyKind> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner
+            .entry(&inner.metadata, currency)
+            .map(|metadata| metadata.get_kind())
+    }
+
+    /// Returns the name to show users for a currency: the Unicode display name from its
+    /// metadata if set, otherwise the currency's ASCII name.
+    ///
+    /// Like [`get_metadata`](Self::get_metadata), only exactly the registered currency
+    /// definition has a display name from metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use finmoney::{FinCurrencyKind, FinCurrencyMetadata, FinMoneyCurrencyRegistry, iso4217};
+    ///
+    /// let registry = FinMoneyCurrencyRegistry::with_currencies([iso4217::PLN])?;
+    /// assert_eq!(registry.get_display_name(&iso4217::PLN).as_deref(), Some("Zloty"));
+    ///
+    /// registry.set_metadata(
+    ///     iso4217::PLN,
+    ///     FinCurrencyMetadata::new(FinCurrencyKind::Fiat).with_display_name("Złoty"),
+    /// )?;
+    /// assert_eq!(registry.get_display_name(&iso4217::PLN).as_deref(), Some("Złoty"));
+    /// # Ok::<(), finmoney::FinMoneyError>(())
+    /// ```
+    pub fn get_display_name(&self, currency: &FinMoneyCurrency) -> Option<String> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner
+            .entry(&inner.metadata, currency)
+            .and_then(|metadata| metadata.get_display_name())
+            .or(currency.get_name())
+            .map(str::to_string)
+    }
+
+    /// Returns the symbol of a registered currency, if its metadata has one.
+    pub fn get_symbol(&self, currency: &FinMoneyCurrency) -> Option<String> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner
+            .entry(&inner.metadata, currency)
+            .and_then(|metadata| metadata.get_symbol())
+            .map(str::to_string)
+    }
+
+    /// Returns the registered currencies of the given kind, sorted by id.
+    pub fn currencies_of_kind(&self, kind: FinCurrencyKind) -> Vec<FinMoneyCurrency> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        let mut currencies: Vec<_> = inner
+            .metadata
+            .iter()
+            .filter(|(_, metadata)| metadata.get_kind() == kind)
+            .filter_map(|(id, _)| inner.by_id.get(id).copied())
+            .collect();
+        currencies.sort_by_key(|c| c.get_id());
+        currencies
+    }
+
+    /// Attaches a denomination unit to a registered currency, replacing any unit with the same
+    /// name, including a built-in one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::UnknownCurrency` if `currency` is not registered.
+    pub fn set_unit(&self, currency: FinMoneyCurrency, unit: FinDenomination) -> Result<()> {
+        let mut inner = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        inner.check_registered(&currency)?;
+        inner
+            .units
+            .entry(currency.get_id())
+            .or_default()
+            .insert(unit.get_name().to_string(), unit.get_factor());
+        Ok(())
+    }
+
+    /// Returns the unit `name` of a currency: one attached with [`set_unit`](Self::set_unit),
+    /// otherwise a built-in one.
+    pub fn get_unit(&self, currency: &FinMoneyCurrency, name: &str) -> Option<FinDenomination> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        match inner
+            .entry(&inner.units, currency)
+            .and_then(|units| units.get(name))
+        {
+            Some(factor) => FinDenomination::new(name, *factor).ok(),
+            None => FinDenomination::builtin(currency, name),
+        }
+    }
+
+    /// Returns every unit of a currency, attached and built-in, from smallest to largest.
+    pub fn get_units(&self, currency: &FinMoneyCurrency) -> Vec<FinDenomination> {
+        let mut units: BTreeMap<String, Decimal> = FinDenomination::builtins(currency)
+            .into_iter()
+            .map(|unit| (unit.get_name().to_string(), unit.get_factor()))
+            .collect();
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(attached) = inner.entry(&inner.units, currency) {
+            units.extend(
+                attached
+                    .iter()
+                    .map(|(name, factor)| (name.clone(), *factor)),
+            );
+        }
+
+        let mut units: Vec<_> = units
+            .into_iter()
+            .filter_map(|(name, factor)| FinDenomination::new(name, factor).ok())
+            .collect();
+        units.sort_by(|a, b| {
+            a.get_factor()
+                .cmp(&b.get_factor())
+                .then_with(|| a.get_name().cmp(b.get_name()))
+        });
+        units
+    }
+
+    /// Returns all registered currencies, sorted by id.
+    pub fn currencies(&self) -> Vec<FinMoneyCurrency> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        let mut currencies: Vec<_> = inner.by_id.values().copied().collect();
+        currencies.sort_by_key(|c| c.get_id());
+        currencies
+    }
+}
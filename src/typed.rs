@@ -0,0 +1,398 @@
+//! Compile-time currency-typed money.
+//!
+//! [`TypedMoney<C>`] carries its currency in the type parameter `C`, so mixing currencies is
+//! a compile error and `+`/`-` need no runtime currency check:
+//!
+//! ```rust
+//! use finmoney::typed::{TypedMoney, USD};
+//! use rust_decimal_macros::dec;
+//!
+//! let price = TypedMoney::<USD>::new(dec!(10.50));
+//! let tax = TypedMoney::<USD>::new(dec!(1.05));
+//! assert_eq!((price + tax).get_amount(), dec!(11.55));
+//! ```
+//!
+//! ```rust,compile_fail
+//! use finmoney::typed::{EUR, TypedMoney, USD};
+//! use rust_decimal_macros::dec;
+//!
+//! let usd = TypedMoney::<USD>::new(dec!(1));
+//! let eur = TypedMoney::<EUR>::new(dec!(1));
+//! let _ = usd + eur; // mismatched types
+//! ```
+//!
+//! Like the arithmetic operators on primitive integers, the operators panic if the amount
+//! overflows `Decimal`. Use the `checked_*` or `saturating_*` methods where that can happen.
+//!
+//! Use [`currency_marker!`](crate::currency_marker) to declare markers for other currencies.
+
+use crate::{FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
+use rust_decimal::Decimal;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A zero-sized type identifying a currency at compile time.
+pub trait CurrencyMarker: Copy + 'static {
+    /// The currency represented by this marker.
+    const CURRENCY: FinMoneyCurrency;
+}
+
+/// Declares a zero-sized currency marker type for use with [`TypedMoney`](crate::typed::TypedMoney).
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{currency_marker, iso4217};
+/// use finmoney::typed::TypedMoney;
+/// use rust_decimal_macros::dec;
+///
+/// currency_marker!(
+///     /// Japanese Yen.
+///     pub JPY => iso4217::JPY
+/// );
+///
+/// let fare = TypedMoney::<JPY>::new(dec!(210));
+/// assert_eq!(fare.get_currency().get_precision(), 0);
+/// ```
+#[macro_export]
+macro_rules! currency_marker {
+    ($(#[$meta:meta])* $vis:vis $name:ident => $currency:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #[allow(clippy::upper_case_acronyms)]
+        $vis struct $name;
+
+        impl $crate::typed::CurrencyMarker for $name {
+            const CURRENCY: $crate::FinMoneyCurrency = $currency;
+        }
+    };
+}
+
+currency_marker!(
+    /// Marker for [`FinMoneyCurrency::USD`].
+    pub USD => FinMoneyCurrency::USD
+);
+currency_marker!(
+    /// Marker for [`FinMoneyCurrency::EUR`].
+    pub EUR => FinMoneyCurrency::EUR
+);
+currency_marker!(
+    /// Marker for [`FinMoneyCurrency::BTC`].
+    pub BTC => FinMoneyCurrency::BTC
+);
+currency_marker!(
+    /// Marker for [`FinMoneyCurrency::ETH`].
+    pub ETH => FinMoneyCurrency::ETH
+);
+
+/// A monetary amount whose currency is fixed by the type parameter `C`.
+///
+/// Arithmetic between values of the same `TypedMoney<C>` needs no currency check, and the value
+/// converts losslessly into a [`FinMoney`]. Converting a [`FinMoney`] back is checked with
+/// `TryFrom`.
+///
+/// The `+`, `-`, `+=`, `-=` and `* Decimal` operators are infallible and panic on overflow.
+/// Use [`checked_add`](Self::checked_add), [`checked_sub`](Self::checked_sub) and
+/// [`checked_mul_decimal`](Self::checked_mul_decimal) to get
+/// `FinMoneyError::ArithmeticOverflow` instead, or the `saturating_*` methods to clamp to the
+/// currency's range.
+pub struct TypedMoney<C: CurrencyMarker> {
+    amount: Decimal,
+    currency: PhantomData<C>,
+}
+
+impl<C: CurrencyMarker> TypedMoney<C> {
+    /// Creates a new typed amount.
+    pub fn new(amount: Decimal) -> Self {
+        Self {
+            amount,
+            currency: PhantomData,
+        }
+    }
+
+    /// Creates a new typed amount rounded to the currency's precision.
+    pub fn new_with_precision(amount: Decimal, strategy: FinMoneyRoundingStrategy) -> Self {
+        Self::new(FinMoney::new_with_precision(amount, C::CURRENCY, strategy).get_amount())
+    }
+
+    /// Returns a zero amount.
+    pub fn zero() -> Self {
+        Self::new(Decimal::ZERO)
+    }
+
+    /// Returns the amount as a `Decimal`.
+    #[inline]
+    pub fn get_amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Returns the currency of the marker `C`.
+    #[inline]
+    pub fn get_currency(&self) -> FinMoneyCurrency {
+        C::CURRENCY
+    }
+
+    /// Converts this value into a dynamically typed [`FinMoney`].
+    pub fn to_fin_money(&self) -> FinMoney {
+        FinMoney::new(self.amount, C::CURRENCY)
+    }
+
+    /// Adds another amount of the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn checked_add(&self, other: Self) -> Result<Self, FinMoneyError> {
+        self.amount
+            .checked_add(other.amount)
+            .map(Self::new)
+            .ok_or(FinMoneyError::ArithmeticOverflow)
+    }
+
+    /// Subtracts another amount of the same currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn checked_sub(&self, other: Self) -> Result<Self, FinMoneyError> {
+        self.amount
+            .checked_sub(other.amount)
+            .map(Self::new)
+            .ok_or(FinMoneyError::ArithmeticOverflow)
+    }
+
+    /// Multiplies by a `Decimal`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn checked_mul_decimal(&self, d: Decimal) -> Result<Self, FinMoneyError> {
+        self.amount
+            .checked_mul(d)
+            .map(Self::new)
+            .ok_or(FinMoneyError::ArithmeticOverflow)
+    }
+
+    /// Divides by a `Decimal`, rounding to the currency's precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::DivisionByZero` if the divisor is zero.
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn divided_by_decimal(
+        &self,
+        d: Decimal,
+        round_strategy: FinMoneyRoundingStrategy,
+    ) -> Result<Self, FinMoneyError> {
+        self.to_fin_money()
+            .divided_by_decimal(d, round_strategy)
+            .map(|m| Self::new(m.get_amount()))
+    }
+
+    /// Adds another amount in place.
+    ///
+    /// On error, `self` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn try_add_assign(&mut self, other: Self) -> Result<(), FinMoneyError> {
+        *self = self.checked_add(other)?;
+        Ok(())
+    }
+
+    /// Subtracts another amount in place.
+    ///
+    /// On error, `self` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::ArithmeticOverflow` if the result is out of range.
+    pub fn try_sub_assign(&mut self, other: Self) -> Result<(), FinMoneyError> {
+        *self = self.checked_sub(other)?;
+        Ok(())
+    }
+
+    /// Adds another amount, clamping a result that overflows the currency range.
+    ///
+    /// See [`FinMoney::saturating_add`].
+    pub fn saturating_add(&self, other: Self) -> Self {
+        let amount = self.amount.checked_add(other.amount);
+        self.saturate(amount, other.amount.is_sign_negative())
+    }
+
+    /// Subtracts another amount, clamping a result that overflows the currency range.
+    ///
+    /// See [`FinMoney::saturating_sub`].
+    pub fn saturating_sub(&self, other: Self) -> Self {
+        let amount = self.amount.checked_sub(other.amount);
+        self.saturate(amount, other.amount.is_sign_positive())
+    }
+
+    /// Multiplies by a `Decimal`, clamping a result that overflows the currency range.
+    ///
+    /// See [`FinMoney::saturating_mul_decimal`].
+    pub fn saturating_mul_decimal(&self, d: Decimal) -> Self {
+        Self::new(self.to_fin_money().saturating_mul_decimal(d).get_amount())
+    }
+
+    /// Clamps the result of an operation on `self` into the currency range.
+    fn saturate(&self, amount: Option<Decimal>, overflow_negative: bool) -> Self {
+        Self::new(
+            self.to_fin_money()
+                .saturate(amount, overflow_negative)
+                .get_amount(),
+        )
+    }
+}
+
+impl<C: CurrencyMarker> From<TypedMoney<C>> for FinMoney {
+    fn from(value: TypedMoney<C>) -> Self {
+        value.to_fin_money()
+    }
+}
+
+impl<C: CurrencyMarker> TryFrom<FinMoney> for TypedMoney<C> {
+    type Error = FinMoneyError;
+
+    /// Converts a [`FinMoney`] whose currency id, code and precision match the marker `C`.
+    fn try_from(value: FinMoney) -> Result<Self, Self::Error> {
+        let currency = value.get_currency();
+        if !currency.is_same_currency(&C::CURRENCY)
+            || currency.get_code() != C::CURRENCY.get_code()
+            || currency.get_precision() != C::CURRENCY.get_precision()
+        {
+            return Err(FinMoneyError::CurrencyMismatch {
+                expected: C::CURRENCY.get_code().to_string(),
+                actual: currency.get_code().to_string(),
+                index: None,
+            });
+        }
+        Ok(Self::new(value.get_amount()))
+    }
+}
+
+// Manual impls so that no bounds beyond `CurrencyMarker` are placed on `C`.
+
+impl<C: CurrencyMarker> Clone for TypedMoney<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: CurrencyMarker> Copy for TypedMoney<C> {}
+
+impl<C: CurrencyMarker> PartialEq for TypedMoney<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.amount == other.amount
+    }
+}
+
+impl<C: CurrencyMarker> Eq for TypedMoney<C> {}
+
+impl<C: CurrencyMarker> PartialOrd for TypedMoney<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: CurrencyMarker> Ord for TypedMoney<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.amount.cmp(&other.amount)
+    }
+}
+
+impl<C: CurrencyMarker> Hash for TypedMoney<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.amount.hash(state);
+    }
+}
+
+impl<C: CurrencyMarker> Default for TypedMoney<C> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<C: CurrencyMarker> fmt::Debug for TypedMoney<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedMoney")
+            .field("amount", &self.amount)
+            .field("currency", &C::CURRENCY.get_code())
+            .finish()
+    }
+}
+
+impl<C: CurrencyMarker> fmt::Display for TypedMoney<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, C::CURRENCY.get_code())
+    }
+}
+
+// -- Operator Overloads --
+
+impl<C: CurrencyMarker> Add for TypedMoney<C> {
+    type Output = TypedMoney<C>;
+
+    /// # Panics
+    ///
+    /// Panics if the result overflows `Decimal`; use [`TypedMoney::checked_add`] to handle it.
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("TypedMoney addition overflowed")
+    }
+}
+
+impl<C: CurrencyMarker> Sub for TypedMoney<C> {
+    type Output = TypedMoney<C>;
+
+    /// # Panics
+    ///
+    /// Panics if the result overflows `Decimal`; use [`TypedMoney::checked_sub`] to handle it.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("TypedMoney subtraction overflowed")
+    }
+}
+
+impl<C: CurrencyMarker> Mul<Decimal> for TypedMoney<C> {
+    type Output = TypedMoney<C>;
+
+    /// # Panics
+    ///
+    /// Panics if the result overflows `Decimal`; use [`TypedMoney::checked_mul_decimal`] to
+    /// handle it.
+    fn mul(self, rhs: Decimal) -> Self::Output {
+        self.checked_mul_decimal(rhs)
+            .expect("TypedMoney multiplication overflowed")
+    }
+}
+
+impl<C: CurrencyMarker> AddAssign for TypedMoney<C> {
+    /// # Panics
+    ///
+    /// Panics if the result overflows `Decimal`; use [`TypedMoney::try_add_assign`] to handle it.
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<C: CurrencyMarker> SubAssign for TypedMoney<C> {
+    /// # Panics
+    ///
+    /// Panics if the result overflows `Decimal`; use [`TypedMoney::try_sub_assign`] to handle it.
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<C: CurrencyMarker> Neg for TypedMoney<C> {
+    type Output = TypedMoney<C>;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.amount)
+    }
+}
@@ -0,0 +1,276 @@
+//! Tiered distribution of proceeds (waterfalls).
+//!
+//! A waterfall pays a total out through a sequence of tiers, e.g. return of capital, preferred
+//! return, catch-up and carried-interest split. Each tier absorbs up to its cap and divides what
+//! it absorbs between parties by fixed weights; whatever is left flows to the next tier.
+
+use crate::{FinMoney, FinMoneyError};
+use rust_decimal::Decimal;
+
+/// One tier of a [`FinMoneyWaterfall`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinMoneyWaterfallTier {
+    name: String,
+    priority: u32,
+    cap: Option<FinMoney>,
+    hurdle: Option<FinMoney>,
+    splits: Vec<(String, Decimal)>,
+}
+
+impl FinMoneyWaterfallTier {
+    /// Creates an uncapped tier without parties.
+    ///
+    /// Tiers are paid in ascending `priority`; tiers with equal priority are paid in the order
+    /// they were added to the waterfall.
+    pub fn new(name: impl Into<String>, priority: u32) -> Self {
+        Self {
+            name: name.into(),
+            priority,
+            cap: None,
+            hurdle: None,
+            splits: Vec::new(),
+        }
+    }
+
+    /// Limits the total this tier absorbs.
+    pub fn with_cap(mut self, cap: FinMoney) -> Self {
+        self.cap = Some(cap);
+        self
+    }
+
+    /// Stops the distribution at this tier unless the total being distributed is at least
+    /// `hurdle`.
+    ///
+    /// The hurdle is measured against the whole total passed to
+    /// [`FinMoneyWaterfall::distribute`], not against what is left when this tier is reached,
+    /// so it expresses conditions such as "the preferred return is only paid once proceeds
+    /// reach 100". If the hurdle is missed, this tier and every tier after it receive nothing
+    /// and the rest of the total is held back as the remainder.
+    pub fn with_hurdle(mut self, hurdle: FinMoney) -> Self {
+        self.hurdle = Some(hurdle);
+        self
+    }
+
+    /// Adds a party receiving `weight` of this tier's amount.
+    ///
+    /// Weights are relative: `(0.8, 0.2)` and `(80, 20)` split the same way.
+    pub fn with_split(mut self, party: impl Into<String>, weight: Decimal) -> Self {
+        self.splits.push((party.into(), weight));
+        self
+    }
+
+    /// Returns the name of this tier.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the priority of this tier.
+    pub fn get_priority(&self) -> u32 {
+        self.priority
+    }
+
+    /// Returns the cap of this tier, if any.
+    pub fn get_cap(&self) -> Option<FinMoney> {
+        self.cap
+    }
+
+    /// Returns the hurdle of this tier, if any.
+    pub fn get_hurdle(&self) -> Option<FinMoney> {
+        self.hurdle
+    }
+
+    /// Returns the parties of this tier with their weights.
+    pub fn get_splits(&self) -> &[(String, Decimal)] {
+        &self.splits
+    }
+
+    /// Checks the tier's definition against the total being distributed.
+    fn validate(&self, total: FinMoney) -> Result<(), FinMoneyError> {
+        if self.splits.is_empty() {
+            return Err(FinMoneyError::InvalidAllocation(format!(
+                "tier '{}' has no parties",
+                self.name
+            )));
+        }
+        if self
+            .splits
+            .iter()
+            .any(|(_, weight)| weight.is_sign_negative())
+        {
+            return Err(FinMoneyError::InvalidAllocation(format!(
+                "tier '{}' has a negative weight",
+                self.name
+            )));
+        }
+        if self.splits.iter().all(|(_, weight)| weight.is_zero()) {
+            return Err(FinMoneyError::InvalidAllocation(format!(
+                "tier '{}' has weights summing to zero",
+                self.name
+            )));
+        }
+        for (label, limit) in [("cap", self.cap), ("hurdle", self.hurdle)] {
+            if let Some(limit) = limit {
+                total.assert_same_currency(limit)?;
+                if limit.is_negative() {
+                    return Err(FinMoneyError::InvalidAllocation(format!(
+                        "tier '{}' has negative {} {}",
+                        self.name, label, limit
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A sequence of tiers distributing a `FinMoney` total between parties.
+///
+/// # Examples
+///
+/// ```rust
+/// use finmoney::{FinMoney, FinMoneyCurrency, FinMoneyWaterfall, FinMoneyWaterfallTier};
+/// use rust_decimal_macros::dec;
+///
+/// let usd = FinMoneyCurrency::USD;
+/// let waterfall = FinMoneyWaterfall::new()
+///     .with_tier(
+///         FinMoneyWaterfallTier::new("return of capital", 1)
+///             .with_cap(FinMoney::new(dec!(1000), usd))
+///             .with_split("LP", dec!(1)),
+///     )
+///     .with_tier(
+///         FinMoneyWaterfallTier::new("carry", 2)
+///             .with_split("LP", dec!(0.8))
+///             .with_split("GP", dec!(0.2)),
+///     );
+///
+/// let result = waterfall.distribute(FinMoney::new(dec!(1500), usd))?;
+/// assert_eq!(result.get_allocation("LP"), Some(FinMoney::new(dec!(1400), usd)));
+/// assert_eq!(result.get_allocation("GP"), Some(FinMoney::new(dec!(100), usd)));
+/// assert!(result.get_remainder().is_zero());
+/// # Ok::<(), finmoney::FinMoneyError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinMoneyWaterfall {
+    tiers: Vec<FinMoneyWaterfallTier>,
+}
+
+impl FinMoneyWaterfall {
+    /// Creates an empty waterfall.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a tier.
+    pub fn with_tier(mut self, tier: FinMoneyWaterfallTier) -> Self {
+        self.tiers.push(tier);
+        self
+    }
+
+    /// Returns the tiers in the order they were added.
+    pub fn get_tiers(&self) -> &[FinMoneyWaterfallTier] {
+        &self.tiers
+    }
+
+    /// Distributes `total` through the tiers.
+    ///
+    /// Each tier's amount is split between its parties with [`FinMoney::allocate`], so every
+    /// allocation is exact to the currency precision and the allocations plus the remainder
+    /// always sum to `total`. The first tier whose hurdle `total` misses gates the tiers after
+    /// it, leaving the rest in the remainder. Every tier is validated before anything is
+    /// distributed, including tiers that are gated or receive nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FinMoneyError::InvalidAmount` if `total` is negative or has more decimal places
+    /// than its currency's precision.
+    /// Returns `FinMoneyError::InvalidAllocation` if a tier has no parties, invalid weights, or a
+    /// negative cap or hurdle.
+    /// Returns `FinMoneyError::CurrencyMismatch` if a cap or hurdle is in a different currency
+    /// than `total`.
+    pub fn distribute(&self, total: FinMoney) -> Result<FinMoneyWaterfallResult, FinMoneyError> {
+        if total.is_negative() {
+            return Err(FinMoneyError::InvalidAmount(format!(
+                "cannot distribute negative total {}",
+                total
+            )));
+        }
+
+        for tier in &self.tiers {
+            tier.validate(total)?;
+        }
+
+        let mut tiers: Vec<&FinMoneyWaterfallTier> = self.tiers.iter().collect();
+        tiers.sort_by_key(|tier| tier.priority);
+
+        let mut available = total;
+        let mut allocations: Vec<(String, FinMoney)> = Vec::new();
+        let mut tier_totals = Vec::with_capacity(tiers.len());
+
+        let mut gated = false;
+        for tier in tiers {
+            if let Some(hurdle) = tier.hurdle {
+                gated |= total.is_less_than(hurdle)?;
+            }
+            let take = match tier.cap {
+                _ if gated => FinMoney::zero(total.get_currency()),
+                Some(cap) => available.min(cap)?,
+                None => available,
+            };
+
+            let weights: Vec<Decimal> = tier.splits.iter().map(|(_, weight)| *weight).collect();
+            let shares = take.allocate(&weights)?;
+            for ((party, _), share) in tier.splits.iter().zip(shares) {
+                match allocations.iter_mut().find(|(p, _)| p == party) {
+                    Some((_, amount)) => *amount = amount.checked_add(share)?,
+                    None => allocations.push((party.clone(), share)),
+                }
+            }
+
+            available = available.checked_sub(take)?;
+            tier_totals.push((tier.name.clone(), take));
+        }
+
+        Ok(FinMoneyWaterfallResult {
+            allocations,
+            tier_totals,
+            remainder: available,
+        })
+    }
+}
+
+/// The outcome of [`FinMoneyWaterfall::distribute`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FinMoneyWaterfallResult {
+    allocations: Vec<(String, FinMoney)>,
+    tier_totals: Vec<(String, FinMoney)>,
+    remainder: FinMoney,
+}
+
+impl FinMoneyWaterfallResult {
+    /// Returns the total allocated to `party` across all tiers, or `None` if no tier names it.
+    pub fn get_allocation(&self, party: &str) -> Option<FinMoney> {
+        self.allocations
+            .iter()
+            .find(|(p, _)| p == party)
+            .map(|(_, amount)| *amount)
+    }
+
+    /// Returns the per-party totals, in order of each party's first tier.
+    pub fn get_allocations(&self) -> &[(String, FinMoney)] {
+        &self.allocations
+    }
+
+    /// Returns the amount absorbed by each tier, in payment order.
+    pub fn get_tier_totals(&self) -> &[(String, FinMoney)] {
+        &self.tier_totals
+    }
+
+    /// Returns the amount left after the last tier.
+    pub fn get_remainder(&self) -> FinMoney {
+        self.remainder
+    }
+}
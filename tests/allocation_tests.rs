@@ -0,0 +1,136 @@
+//! Tests for lossless allocation of FinMoney values.
+
+use finmoney::{
+    FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyIteratorExt, FinMoneyRemainderStrategy,
+    iso4217,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn amounts(shares: &[FinMoney]) -> Vec<Decimal> {
+    shares.iter().map(|s| s.get_amount()).collect()
+}
+
+#[test]
+fn test_allocate_sums_to_original() -> Result<(), FinMoneyError> {
+    let total = FinMoney::new(dec!(100.00), FinMoneyCurrency::USD);
+    let shares = total.allocate(&[dec!(1), dec!(1), dec!(1)])?;
+
+    assert_eq!(
+        amounts(&shares),
+        vec![dec!(33.34), dec!(33.33), dec!(33.33)]
+    );
+    assert_eq!(shares.iter().try_sum()?, Some(total));
+    Ok(())
+}
+
+#[test]
+fn test_remainder_strategies() -> Result<(), FinMoneyError> {
+    let total = FinMoney::new(dec!(0.05), FinMoneyCurrency::USD);
+    let ratios = [dec!(1), dec!(1), dec!(1)];
+
+    let first = total.allocate_with(&ratios, FinMoneyRemainderStrategy::First)?;
+    assert_eq!(amounts(&first), vec![dec!(0.03), dec!(0.01), dec!(0.01)]);
+
+    let last = total.allocate_with(&ratios, FinMoneyRemainderStrategy::Last)?;
+    assert_eq!(amounts(&last), vec![dec!(0.01), dec!(0.01), dec!(0.03)]);
+
+    let round_robin = total.allocate_with(&ratios, FinMoneyRemainderStrategy::RoundRobin)?;
+    assert_eq!(
+        amounts(&round_robin),
+        vec![dec!(0.02), dec!(0.02), dec!(0.01)]
+    );
+
+    // 0.05 * (1, 3, 6) / 10 = 0.005, 0.015, 0.030: fractions favour the first two shares.
+    let largest = total.allocate_with(
+        &[dec!(1), dec!(3), dec!(6)],
+        FinMoneyRemainderStrategy::LargestFraction,
+    )?;
+    assert_eq!(amounts(&largest), vec![dec!(0.01), dec!(0.01), dec!(0.03)]);
+    Ok(())
+}
+
+#[test]
+fn test_largest_fraction() -> Result<(), FinMoneyError> {
+    // 10.00 * (0.2, 0.45, 0.35) = 2.00, 4.50, 3.50 -> exact
+    let total = FinMoney::new(dec!(10.00), FinMoneyCurrency::USD);
+    let shares = total.allocate(&[dec!(0.2), dec!(0.45), dec!(0.35)])?;
+    assert_eq!(amounts(&shares), vec![dec!(2.00), dec!(4.50), dec!(3.50)]);
+
+    // 1 JPY * (1, 2) / 3 = 0.333, 0.667 -> second share has the larger fraction
+    let yen = FinMoney::new(dec!(1), iso4217::JPY);
+    let shares = yen.allocate(&[dec!(1), dec!(2)])?;
+    assert_eq!(amounts(&shares), vec![dec!(0), dec!(1)]);
+    Ok(())
+}
+
+#[test]
+fn test_zero_ratios_receive_nothing() -> Result<(), FinMoneyError> {
+    let total = FinMoney::new(dec!(0.05), FinMoneyCurrency::USD);
+    for strategy in [
+        FinMoneyRemainderStrategy::First,
+        FinMoneyRemainderStrategy::Last,
+        FinMoneyRemainderStrategy::RoundRobin,
+        FinMoneyRemainderStrategy::LargestFraction,
+    ] {
+        let shares = total.allocate_with(&[dec!(0), dec!(1), dec!(1), dec!(0)], strategy)?;
+        assert!(shares[0].is_zero());
+        assert!(shares[3].is_zero());
+        assert_eq!(shares.iter().try_sum()?, Some(total));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_negative_amount() -> Result<(), FinMoneyError> {
+    let refund = FinMoney::new(dec!(-100.00), FinMoneyCurrency::USD);
+    let shares = refund.split_evenly(3)?;
+    assert_eq!(
+        amounts(&shares),
+        vec![dec!(-33.34), dec!(-33.33), dec!(-33.33)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_split_evenly() -> Result<(), FinMoneyError> {
+    let total = FinMoney::new(dec!(10), FinMoneyCurrency::USD);
+    let shares = total.split_evenly(7)?;
+    assert_eq!(shares.len(), 7);
+    assert_eq!(
+        shares.iter().try_sum()?,
+        Some(FinMoney::new(dec!(10.00), FinMoneyCurrency::USD))
+    );
+    assert!(shares.iter().all(|s| s.get_amount().scale() <= 2));
+    Ok(())
+}
+
+#[test]
+fn test_allocation_errors() {
+    let total = FinMoney::new(dec!(10), FinMoneyCurrency::USD);
+
+    assert!(matches!(
+        total.allocate(&[]),
+        Err(FinMoneyError::InvalidAllocation(_))
+    ));
+    assert!(matches!(
+        total.allocate(&[dec!(0), dec!(0)]),
+        Err(FinMoneyError::InvalidAllocation(_))
+    ));
+    assert!(matches!(
+        total.allocate(&[dec!(1), dec!(-1)]),
+        Err(FinMoneyError::InvalidAllocation(_))
+    ));
+    assert!(matches!(
+        total.split_evenly(0),
+        Err(FinMoneyError::InvalidAllocation(_))
+    ));
+    assert!(matches!(
+        FinMoney::new(dec!(10.005), FinMoneyCurrency::USD).split_evenly(2),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+    assert!(matches!(
+        FinMoney::new(Decimal::MAX, FinMoneyCurrency::USD).split_evenly(2),
+        Err(FinMoneyError::ArithmeticOverflow)
+    ));
+}
@@ -0,0 +1,147 @@
+//! Tests for arbitrage cycle detection.
+
+use finmoney::{
+    FinArbitrageDetector, FinExchangeRate, FinMoney, FinMoneyCurrency, FinMoneyError, iso4217,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn rate(
+    base: FinMoneyCurrency,
+    quote: FinMoneyCurrency,
+    rate: Decimal,
+) -> Result<FinExchangeRate, FinMoneyError> {
+    FinExchangeRate::new(base, quote, rate)
+}
+
+fn triangle() -> Result<FinArbitrageDetector, FinMoneyError> {
+    let mut detector = FinArbitrageDetector::new();
+    detector.insert(rate(iso4217::USD, iso4217::EUR, dec!(0.92))?);
+    detector.insert(rate(iso4217::EUR, iso4217::GBP, dec!(0.86))?);
+    detector.insert(rate(iso4217::GBP, iso4217::USD, dec!(1.28))?);
+    Ok(detector)
+}
+
+#[test]
+fn test_finds_triangular_cycle() -> Result<(), FinMoneyError> {
+    let start = FinMoney::new(dec!(10000), iso4217::USD);
+    let cycles = triangle()?.find_cycles(&start, 3)?;
+
+    assert_eq!(cycles.len(), 1);
+    let cycle = &cycles[0];
+    assert_eq!(
+        cycle.get_path(),
+        vec![iso4217::USD, iso4217::EUR, iso4217::GBP, iso4217::USD]
+    );
+    assert_eq!(cycle.get_start(), start);
+    assert_eq!(cycle.get_end(), FinMoney::new(dec!(10127.36), iso4217::USD));
+    assert_eq!(cycle.get_gain(), FinMoney::new(dec!(127.36), iso4217::USD));
+    assert_eq!(cycle.get_hops().len(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_hop_limit() -> Result<(), FinMoneyError> {
+    let start = FinMoney::new(dec!(10000), iso4217::USD);
+    assert!(triangle()?.find_cycles(&start, 2)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_fees_remove_profit() -> Result<(), FinMoneyError> {
+    let mut detector = triangle()?;
+    // 1.012736 gross; three 0.5% fees leave less than the start.
+    for (base, quote, r) in [
+        (iso4217::USD, iso4217::EUR, dec!(0.92)),
+        (iso4217::EUR, iso4217::GBP, dec!(0.86)),
+        (iso4217::GBP, iso4217::USD, dec!(1.28)),
+    ] {
+        detector.insert_with_fee(rate(base, quote, r)?, dec!(0.005))?;
+    }
+
+    let start = FinMoney::new(dec!(10000), iso4217::USD);
+    assert!(detector.find_cycles(&start, 3)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_small_fees_reduce_gain() -> Result<(), FinMoneyError> {
+    let mut detector = triangle()?;
+    detector.insert_with_fee(rate(iso4217::USD, iso4217::EUR, dec!(0.92))?, dec!(0.001))?;
+
+    let start = FinMoney::new(dec!(10000), iso4217::USD);
+    let cycles = detector.find_cycles(&start, 3)?;
+    // 9190.80 EUR -> 7904.08 GBP -> 10117.22 USD, each hop rounded toward zero
+    assert_eq!(
+        cycles[0].get_gain(),
+        FinMoney::new(dec!(117.22), iso4217::USD)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_orders_by_profit() -> Result<(), FinMoneyError> {
+    let mut detector = triangle()?;
+    detector.insert(rate(iso4217::USD, iso4217::JPY, dec!(150))?);
+    detector.insert(rate(iso4217::JPY, iso4217::USD, dec!(0.0067))?);
+
+    let start = FinMoney::new(dec!(1000), iso4217::USD);
+    let cycles = detector.find_cycles(&start, 4)?;
+
+    // USD -> JPY -> USD: 1000 * 150 * 0.0067 = 1005
+    assert_eq!(cycles.len(), 2);
+    assert_eq!(cycles[0].get_gain().get_amount(), dec!(12.73));
+    assert_eq!(cycles[1].get_gain().get_amount(), dec!(5));
+    assert_eq!(cycles[1].get_path().len(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_invalid_fee() -> Result<(), FinMoneyError> {
+    let mut detector = FinArbitrageDetector::new();
+    let r = rate(iso4217::USD, iso4217::EUR, dec!(0.92))?;
+    assert!(matches!(
+        detector.insert_with_fee(r, dec!(1)),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+    assert!(matches!(
+        detector.insert_with_fee(r, dec!(-0.01)),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_start_must_be_positive() -> Result<(), FinMoneyError> {
+    let mut detector = triangle()?;
+    // A losing cycle: 0.92 * 0.86 * 1.2 < 1. Rounding a negative start toward zero
+    // would otherwise report it as a gain.
+    detector.insert(rate(iso4217::GBP, iso4217::USD, dec!(1.2))?);
+
+    for amount in [dec!(-10000), dec!(0)] {
+        let start = FinMoney::new(amount, iso4217::USD);
+        assert!(matches!(
+            detector.find_cycles(&start, 3),
+            Err(FinMoneyError::InvalidAmount(_))
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_self_rates_are_ignored() -> Result<(), FinMoneyError> {
+    let mut detector = triangle()?;
+    detector.insert(rate(iso4217::USD, iso4217::USD, dec!(1))?);
+    detector.insert_with_fee(rate(iso4217::EUR, iso4217::EUR, dec!(1))?, dec!(0.001))?;
+
+    let start = FinMoney::new(dec!(10000), iso4217::USD);
+    assert!(detector.find_cycles(&start, 1)?.is_empty());
+
+    let cycles = detector.find_cycles(&start, 4)?;
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(
+        cycles[0].get_path(),
+        vec![iso4217::USD, iso4217::EUR, iso4217::GBP, iso4217::USD]
+    );
+    Ok(())
+}
@@ -0,0 +1,198 @@
+//! Tests for the multi-currency FinMoneyBag.
+
+use finmoney::{FinMoney, FinMoneyBag, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn usd(amount: Decimal) -> FinMoney {
+    FinMoney::new(amount, FinMoneyCurrency::USD)
+}
+
+fn eur(amount: Decimal) -> FinMoney {
+    FinMoney::new(amount, FinMoneyCurrency::EUR)
+}
+
+fn btc(amount: Decimal) -> FinMoney {
+    FinMoney::new(amount, FinMoneyCurrency::BTC)
+}
+
+#[test]
+fn test_add_and_get() -> Result<(), FinMoneyError> {
+    let mut bag = FinMoneyBag::new();
+    assert!(bag.is_empty());
+
+    bag.add(usd(dec!(10)))?;
+    bag.add(eur(dec!(5)))?;
+    bag.add(usd(dec!(2.50)))?;
+
+    assert_eq!(bag.len(), 2);
+    assert_eq!(bag.get(FinMoneyCurrency::USD), usd(dec!(12.50)));
+    assert_eq!(bag.get(FinMoneyCurrency::EUR), eur(dec!(5)));
+    assert_eq!(bag.get(FinMoneyCurrency::BTC), btc(dec!(0)));
+    assert!(!bag.contains(FinMoneyCurrency::BTC));
+    Ok(())
+}
+
+#[test]
+fn test_subtract_drops_zero_totals() -> Result<(), FinMoneyError> {
+    let mut bag = FinMoneyBag::from(usd(dec!(10)));
+    bag.subtract(eur(dec!(3)))?;
+    assert_eq!(bag.get(FinMoneyCurrency::EUR), eur(dec!(-3)));
+
+    bag.subtract(usd(dec!(10)))?;
+    assert!(!bag.contains(FinMoneyCurrency::USD));
+    assert_eq!(bag.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_negated_and_merge() -> Result<(), FinMoneyError> {
+    let mut bag = FinMoneyBag::new();
+    bag.add(usd(dec!(10)))?;
+    bag.add(btc(dec!(0.5)))?;
+
+    let negated = bag.negated();
+    assert_eq!(negated.get(FinMoneyCurrency::BTC), btc(dec!(-0.5)));
+
+    let mut other = FinMoneyBag::from(eur(dec!(7)));
+    other.merge(&bag)?;
+    assert_eq!(other.len(), 3);
+
+    other.merge(&negated)?;
+    assert_eq!(other, FinMoneyBag::from(eur(dec!(7))));
+    Ok(())
+}
+
+#[test]
+fn test_merge_overflow_leaves_bag_unchanged() -> Result<(), FinMoneyError> {
+    let mut bag = FinMoneyBag::new();
+    bag.add(eur(dec!(1)))?;
+    bag.add(usd(Decimal::MAX))?;
+    let before = bag.clone();
+
+    let mut other = FinMoneyBag::new();
+    other.add(eur(dec!(1)))?;
+    other.add(usd(dec!(1)))?;
+
+    assert_eq!(bag.merge(&other), Err(FinMoneyError::ArithmeticOverflow));
+    assert_eq!(bag, before);
+    Ok(())
+}
+
+#[test]
+fn test_iteration_is_ordered_by_currency_id() -> Result<(), FinMoneyError> {
+    let mut bag = FinMoneyBag::new();
+    bag.add(btc(dec!(1)))?;
+    bag.add(usd(dec!(1)))?;
+    bag.add(eur(dec!(1)))?;
+
+    let codes: Vec<_> = bag
+        .iter()
+        .map(|m| m.get_currency().get_code().to_string())
+        .collect();
+    assert_eq!(codes, vec!["BTC", "USD", "EUR"]);
+
+    let count = (&bag).into_iter().count();
+    assert_eq!(count, 3);
+    Ok(())
+}
+
+#[test]
+fn test_collapse() -> Result<(), FinMoneyError> {
+    let mut bag = FinMoneyBag::new();
+    bag.add(usd(dec!(100)))?;
+    bag.add(eur(dec!(10.01)))?;
+    bag.add(btc(dec!(0.001)))?;
+
+    let rate = |from: &FinMoneyCurrency, _to: &FinMoneyCurrency| match from.get_code() {
+        "EUR" => Some(dec!(1.0833)),
+        "BTC" => Some(dec!(65000)),
+        _ => None,
+    };
+
+    // 100 + 10.843833 + 65 = 175.843833
+    let total = bag.collapse(
+        FinMoneyCurrency::USD,
+        rate,
+        FinMoneyRoundingStrategy::MidpointNearestEven,
+    )?;
+    assert_eq!(total, usd(dec!(175.84)));
+
+    let up = bag.collapse(
+        FinMoneyCurrency::USD,
+        rate,
+        FinMoneyRoundingStrategy::AwayFromZero,
+    )?;
+    assert_eq!(up, usd(dec!(175.85)));
+    Ok(())
+}
+
+#[test]
+fn test_collapse_missing_rate() {
+    let bag = FinMoneyBag::from(eur(dec!(1)));
+    let result = bag.collapse(
+        FinMoneyCurrency::USD,
+        |_, _| None,
+        FinMoneyRoundingStrategy::default(),
+    );
+    assert_eq!(
+        result,
+        Err(FinMoneyError::MissingExchangeRate {
+            base: "EUR".to_string(),
+            quote: "USD".to_string(),
+        })
+    );
+
+    let empty = FinMoneyBag::new();
+    assert_eq!(
+        empty.collapse(
+            FinMoneyCurrency::USD,
+            |_, _| None,
+            FinMoneyRoundingStrategy::default()
+        ),
+        Ok(usd(dec!(0)))
+    );
+}
+
+#[test]
+fn test_collapse_rejects_non_positive_rates() -> Result<(), FinMoneyError> {
+    let mut bag = FinMoneyBag::from(eur(dec!(10)));
+    bag.add(usd(dec!(5)))?;
+
+    for rate in [dec!(0), dec!(-1.10)] {
+        assert_eq!(
+            bag.collapse(
+                FinMoneyCurrency::USD,
+                |_, _| Some(rate),
+                FinMoneyRoundingStrategy::default()
+            ),
+            Err(FinMoneyError::InvalidExchangeRate(rate))
+        );
+    }
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_deserialize_rebuilds_the_bag() -> Result<(), Box<dyn std::error::Error>> {
+    let mut bag = FinMoneyBag::from(usd(dec!(10)));
+    bag.add(eur(dec!(5)))?;
+    let json = serde_json::to_value(&bag)?;
+    assert!(json.is_array());
+    assert_eq!(serde_json::from_value::<FinMoneyBag>(json)?, bag);
+
+    // Zero totals are dropped.
+    let zero = serde_json::to_value([usd(dec!(10)), eur(dec!(0))])?;
+    let rebuilt = serde_json::from_value::<FinMoneyBag>(zero)?;
+    assert_eq!(rebuilt.len(), 1);
+    assert!(!rebuilt.contains(FinMoneyCurrency::EUR));
+
+    // A currency listed twice is rejected.
+    let duplicate = serde_json::to_value([usd(dec!(10)), usd(dec!(5))])?;
+    assert!(serde_json::from_value::<FinMoneyBag>(duplicate).is_err());
+
+    // A map keyed by currency id cannot smuggle in an amount under the wrong key.
+    let mismatched = serde_json::json!({ "amounts": { "1": serde_json::to_value(eur(dec!(0)))? } });
+    assert!(serde_json::from_value::<FinMoneyBag>(mismatched).is_err());
+    Ok(())
+}
@@ -0,0 +1,142 @@
+//! Tests for fixed-rate changeover conversion.
+
+use finmoney::{
+    FinFixedRateTable, FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy, iso4217,
+};
+use rust_decimal_macros::dec;
+
+struct Legacy {
+    dem: FinMoneyCurrency,
+    frf: FinMoneyCurrency,
+    itl: FinMoneyCurrency,
+    grd: FinMoneyCurrency,
+}
+
+fn euro_table() -> Result<(FinFixedRateTable, Legacy), FinMoneyError> {
+    let legacy = Legacy {
+        dem: FinMoneyCurrency::new(276, "DEM", Some("Deutsche Mark".to_string()), 2)?,
+        frf: FinMoneyCurrency::new(250, "FRF", Some("French Franc".to_string()), 2)?,
+        itl: FinMoneyCurrency::new(380, "ITL", Some("Italian Lira".to_string()), 0)?,
+        grd: FinMoneyCurrency::new(300, "GRD", Some("Greek Drachma".to_string()), 2)?,
+    };
+    let mut table = FinFixedRateTable::new(iso4217::EUR);
+    table.insert(legacy.dem, dec!(1.95583))?;
+    table.insert(legacy.frf, dec!(6.55957))?;
+    table.insert(legacy.itl, dec!(1936.27))?;
+    table.insert(legacy.grd, dec!(340.750))?;
+    Ok((table, legacy))
+}
+
+#[test]
+fn test_legacy_to_new_divides() -> Result<(), FinMoneyError> {
+    let (table, legacy) = euro_table()?;
+
+    // 1000 / 1.95583 = 511.291881...
+    let euros = table.convert(&FinMoney::new(dec!(1000), legacy.dem), iso4217::EUR)?;
+    assert_eq!(euros, FinMoney::new(dec!(511.29), iso4217::EUR));
+    Ok(())
+}
+
+#[test]
+fn test_new_to_legacy_multiplies() -> Result<(), FinMoneyError> {
+    let (table, legacy) = euro_table()?;
+
+    let lire = table.convert(&FinMoney::new(dec!(10.00), iso4217::EUR), legacy.itl)?;
+    assert_eq!(lire, FinMoney::new(dec!(19363), legacy.itl));
+
+    let drachmas = table.convert(&FinMoney::new(dec!(1), iso4217::EUR), legacy.grd)?;
+    assert_eq!(drachmas.get_amount(), dec!(340.75));
+    Ok(())
+}
+
+#[test]
+fn test_legacy_to_legacy_triangulates() -> Result<(), FinMoneyError> {
+    let (table, legacy) = euro_table()?;
+
+    // 100 DEM -> 51.129188 EUR -> 51.129 EUR -> 335.384... FRF
+    let francs = table.convert(&FinMoney::new(dec!(100), legacy.dem), legacy.frf)?;
+    assert_eq!(francs, FinMoney::new(dec!(335.38), legacy.frf));
+
+    // A direct cross rate (6.55957 / 1.95583 = 3.353855...) would give 335.39.
+    let six_decimals = euro_table()?
+        .0
+        .with_intermediate_precision(6)?
+        .convert(&FinMoney::new(dec!(100), legacy.dem), legacy.frf)?;
+    assert_eq!(six_decimals.get_amount(), dec!(335.39));
+    Ok(())
+}
+
+#[test]
+fn test_rounds_half_up() -> Result<(), FinMoneyError> {
+    let (table, legacy) = euro_table()?;
+    assert_eq!(
+        FinFixedRateTable::ROUNDING,
+        FinMoneyRoundingStrategy::MidpointAwayFromZero
+    );
+
+    // 1000 / 6.55957 = 152.449017...
+    let euros = table.convert(&FinMoney::new(dec!(1000), legacy.frf), iso4217::EUR)?;
+    assert_eq!(euros.get_amount(), dec!(152.45));
+    // 0.30 * 340.750 = 102.225, a midpoint rounded away from zero rather than to even
+    let drachmas = table.convert(&FinMoney::new(dec!(0.30), iso4217::EUR), legacy.grd)?;
+    assert_eq!(drachmas.get_amount(), dec!(102.23));
+    let refund = table.convert(&FinMoney::new(dec!(-0.30), iso4217::EUR), legacy.grd)?;
+    assert_eq!(refund.get_amount(), dec!(-102.23));
+    Ok(())
+}
+
+#[test]
+fn test_rate_validation() -> Result<(), FinMoneyError> {
+    let (mut table, legacy) = euro_table()?;
+    let bef = FinMoneyCurrency::new(56, "BEF", None, 0)?;
+
+    for rate in [
+        dec!(40.34),
+        dec!(40.33990),
+        dec!(40.3399001),
+        dec!(0),
+        dec!(-40.3399),
+    ] {
+        assert_eq!(
+            table.insert(bef, rate),
+            Err(FinMoneyError::InvalidExchangeRate(rate)),
+            "{rate}"
+        );
+    }
+    table.insert(bef, dec!(40.3399))?;
+    assert_eq!(table.get_rate(bef), Some(dec!(40.3399)));
+
+    // Re-fixing the same rate is fine, changing it is not.
+    table.insert(legacy.dem, dec!(1.95583))?;
+    assert!(matches!(
+        table.insert(legacy.dem, dec!(1.95584)),
+        Err(FinMoneyError::CurrencyConflict { .. })
+    ));
+    assert!(matches!(
+        table.insert(iso4217::EUR, dec!(1.00000)),
+        Err(FinMoneyError::InvalidCurrencyPair(_))
+    ));
+    assert!(matches!(
+        FinFixedRateTable::new(iso4217::EUR).with_intermediate_precision(2),
+        Err(FinMoneyError::InvalidPrecision(2))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_unknown_currencies() -> Result<(), FinMoneyError> {
+    let (table, legacy) = euro_table()?;
+
+    assert!(matches!(
+        table.convert(&FinMoney::new(dec!(1), iso4217::USD), iso4217::EUR),
+        Err(FinMoneyError::MissingExchangeRate { .. })
+    ));
+    assert!(matches!(
+        table.convert(&FinMoney::new(dec!(1), legacy.dem), iso4217::USD),
+        Err(FinMoneyError::MissingExchangeRate { .. })
+    ));
+
+    let same = FinMoney::new(dec!(1.234), legacy.dem);
+    assert_eq!(table.convert(&same, legacy.dem)?, same);
+    Ok(())
+}
@@ -0,0 +1,173 @@
+//! Tests for loading currency definitions from configuration files.
+
+#![cfg(feature = "config")]
+
+use finmoney::config::{FinMoneyConfigFormat, parse_currencies};
+use finmoney::{FinMoneyCurrency, FinMoneyCurrencyRegistry, FinMoneyError};
+
+const TOML_INPUT: &str = r#"
+[[currency]]
+id = 840
+code = "USD"
+name = "US Dollar"
+precision = 2
+
+[[currency]]
+id = 3
+code = "BTC"
+precision = 8
+"#;
+
+const JSON_INPUT: &str = r#"[
+  { "id": 840, "code": "USD", "name": "US Dollar", "precision": 2 },
+  { "id": 3, "code": "BTC", "precision": 8 }
+]"#;
+
+const CSV_INPUT: &str = "id,code,name,precision\n840,USD,US Dollar,2\n3,BTC,,8\n";
+
+#[test]
+fn test_parse_all_formats() -> Result<(), Box<dyn std::error::Error>> {
+    for (input, format) in [
+        (TOML_INPUT, FinMoneyConfigFormat::Toml),
+        (JSON_INPUT, FinMoneyConfigFormat::Json),
+        (CSV_INPUT, FinMoneyConfigFormat::Csv),
+    ] {
+        let currencies = parse_currencies(input, format).map_err(|e| format!("{:?}", e))?;
+        assert_eq!(currencies.len(), 2, "{:?}", format);
+
+        assert_eq!(currencies[0].get_id(), 840);
+        assert_eq!(currencies[0].get_code(), "USD");
+        assert_eq!(currencies[0].get_name(), Some("US Dollar"));
+        assert_eq!(currencies[0].get_precision(), 2);
+
+        assert_eq!(currencies[1].get_code(), "BTC");
+        assert_eq!(currencies[1].get_name(), None);
+        assert_eq!(currencies[1].get_precision(), 8);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_toml_row_errors_have_line_numbers() {
+    let input = r#"[[currency]]
+id = 1
+code = "USD"
+precision = 29
+
+[[currency]]
+id = 2
+code = "EURO-TOO-LONG-CODE"
+precision = 2
+"#;
+    let errors = parse_currencies(input, FinMoneyConfigFormat::Toml).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].line, Some(1));
+    assert_eq!(errors[0].error, FinMoneyError::InvalidPrecision(29));
+    assert_eq!(errors[1].line, Some(6));
+    assert_eq!(
+        errors[1].error,
+        FinMoneyError::InvalidCurrencyCode("EURO-TOO-LONG-CODE".to_string())
+    );
+}
+
+#[test]
+fn test_json_row_errors_have_line_numbers() {
+    let input = r#"[
+  { "id": 1, "code": "USD", "precision": 2 },
+  { "id": 2, "code": "PLN", "name": "Złoty", "precision": 2 },
+  { "id": 3, "precision": 2 }
+]"#;
+    let errors = parse_currencies(input, FinMoneyConfigFormat::Json).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].line, Some(3));
+    assert_eq!(
+        errors[0].error,
+        FinMoneyError::InvalidCurrencyName("Złoty".to_string())
+    );
+    assert_eq!(errors[1].line, Some(4));
+    assert!(matches!(errors[1].error, FinMoneyError::InvalidConfig(_)));
+}
+
+#[test]
+fn test_csv_row_errors_have_line_numbers() {
+    let input = "id,code,name,precision\n1,USD,US Dollar,2\n2,,Empty,2\n3,ABC,Abc,x\n";
+    let errors = parse_currencies(input, FinMoneyConfigFormat::Csv).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].line, Some(3));
+    assert_eq!(
+        errors[0].error,
+        FinMoneyError::InvalidCurrencyCode(String::new())
+    );
+    assert_eq!(errors[1].line, Some(4));
+    assert!(
+        errors[1]
+            .to_string()
+            .starts_with("line 4: Invalid configuration")
+    );
+}
+
+#[test]
+fn test_syntax_error() {
+    let errors = parse_currencies("[[currency]\nid = 1", FinMoneyConfigFormat::Toml).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, Some(1));
+
+    let errors = parse_currencies("[\n{ \"id\": }", FinMoneyConfigFormat::Json).unwrap_err();
+    assert_eq!(errors[0].line, Some(2));
+}
+
+#[test]
+fn test_registry_load_is_atomic() {
+    let registry = FinMoneyCurrencyRegistry::new();
+    registry.register(FinMoneyCurrency::USD).unwrap();
+
+    let input = "id,code,name,precision\n3,BTC,Bitcoin,8\n7,USD,Clash,2\n";
+    let errors = registry
+        .load_str(input, FinMoneyConfigFormat::Csv)
+        .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].line, Some(3));
+    assert!(matches!(
+        errors[0].error,
+        FinMoneyError::CurrencyConflict { .. }
+    ));
+    assert_eq!(registry.len(), 1);
+    assert!(registry.get_by_code("BTC").is_none());
+
+    let added = registry
+        .load_str(CSV_INPUT, FinMoneyConfigFormat::Csv)
+        .unwrap();
+    assert_eq!(added, 1);
+    assert_eq!(registry.len(), 2);
+}
+
+#[test]
+fn test_registry_load_file() {
+    let dir = std::env::temp_dir();
+    let path = dir.join(format!("finmoney-config-test-{}.json", std::process::id()));
+    std::fs::write(&path, JSON_INPUT).unwrap();
+
+    let registry = FinMoneyCurrencyRegistry::new();
+    let added = registry.load_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(added, 2);
+    assert_eq!(registry.get_by_code("BTC").map(|c| c.get_id()), Some(3));
+
+    let errors = registry.load_file(dir.join("currencies.yaml")).unwrap_err();
+    assert!(matches!(errors[0].error, FinMoneyError::InvalidConfig(_)));
+}
+
+#[test]
+fn test_format_from_path() {
+    assert_eq!(
+        FinMoneyConfigFormat::from_path("assets/currencies.TOML"),
+        Some(FinMoneyConfigFormat::Toml)
+    );
+    assert_eq!(
+        FinMoneyConfigFormat::from_path("a.csv"),
+        Some(FinMoneyConfigFormat::Csv)
+    );
+    assert_eq!(FinMoneyConfigFormat::from_path("a.txt"), None);
+    assert_eq!(FinMoneyConfigFormat::from_path("noext"), None);
+}
@@ -1,6 +1,6 @@
 //! Tests for currency functionality.
 
-use finmoney::{FinMoneyCurrency, FinMoneyError};
+use finmoney::{FinMoneyCurrency, FinMoneyError, iso4217};
 
 #[test]
 fn test_currency_creation() -> Result<(), FinMoneyError> {
@@ -82,12 +82,14 @@ fn test_currency_comparison() {
 #[test]
 fn test_predefined_currencies() {
     let usd = FinMoneyCurrency::USD;
-    assert_eq!(usd.get_id(), 1);
+    assert_eq!(usd, iso4217::USD);
+    assert_eq!(usd.get_id(), 840);
     assert_eq!(usd.get_code(), "USD");
     assert_eq!(usd.get_precision(), 2);
 
     let eur = FinMoneyCurrency::EUR;
-    assert_eq!(eur.get_id(), 2);
+    assert_eq!(eur, iso4217::EUR);
+    assert_eq!(eur.get_id(), 978);
     assert_eq!(eur.get_code(), "EUR");
     assert_eq!(eur.get_precision(), 2);
 
@@ -195,3 +197,18 @@ fn test_currency_new_from_tiny_performance() -> Result<(), FinMoneyError> {
 
     Ok(())
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_deserialize_validates() -> Result<(), Box<dyn std::error::Error>> {
+    let json = serde_json::to_value(FinMoneyCurrency::BTC)?;
+    assert_eq!(
+        serde_json::from_value::<FinMoneyCurrency>(json.clone())?,
+        FinMoneyCurrency::BTC
+    );
+
+    let mut too_precise = json;
+    too_precise["precision"] = 29.into();
+    assert!(serde_json::from_value::<FinMoneyCurrency>(too_precise).is_err());
+    Ok(())
+}
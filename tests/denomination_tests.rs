@@ -0,0 +1,223 @@
+//! Tests for denomination units.
+
+use finmoney::{
+    Decimal, FinDenomination, FinMoney, FinMoneyCurrency, FinMoneyCurrencyRegistry, FinMoneyError,
+};
+use rust_decimal_macros::dec;
+
+fn token_registry(
+    precision: u8,
+    factor: Decimal,
+) -> Result<(FinMoneyCurrency, FinMoneyCurrencyRegistry), FinMoneyError> {
+    let token = FinMoneyCurrency::new(7100, "TOK", None, precision)?;
+    let registry = FinMoneyCurrencyRegistry::with_currencies([token])?;
+    registry.set_unit(token, FinDenomination::new("unit", factor)?)?;
+    Ok((token, registry))
+}
+
+#[test]
+fn test_builtin_bitcoin_units() -> Result<(), FinMoneyError> {
+    let amount = FinMoney::from_unit(dec!(150000), "sat", FinMoneyCurrency::BTC)?;
+    assert_eq!(amount.get_amount(), dec!(0.0015));
+    assert_eq!(amount.to_unit("sat")?, dec!(150000));
+    assert_eq!(amount.to_unit("mBTC")?, dec!(1.5));
+    assert_eq!(amount.to_unit("bit")?, dec!(1500));
+
+    let names: Vec<_> = FinDenomination::builtins(&FinMoneyCurrency::BTC)
+        .iter()
+        .map(|unit| unit.get_name().to_string())
+        .collect();
+    assert_eq!(names, ["sat", "bit", "mBTC"]);
+    Ok(())
+}
+
+#[test]
+fn test_builtin_ether_units() -> Result<(), FinMoneyError> {
+    let one_eth = FinMoney::new(dec!(1), FinMoneyCurrency::ETH);
+    assert_eq!(one_eth.to_unit("wei")?, dec!(1000000000000000000));
+    assert_eq!(one_eth.to_unit("gwei")?, dec!(1000000000));
+
+    let one_wei = FinMoney::from_unit(dec!(1), "wei", FinMoneyCurrency::ETH)?;
+    assert_eq!(one_wei.get_amount(), dec!(0.000000000000000001));
+    assert_eq!(one_wei.to_unit("gwei")?, dec!(0.000000001));
+    Ok(())
+}
+
+#[test]
+fn test_large_mantissas_round_trip() -> Result<(), FinMoneyError> {
+    let wei = Decimal::MAX;
+    let ether = FinMoney::from_unit(wei, "wei", FinMoneyCurrency::ETH)?;
+    assert_eq!(ether.get_amount(), dec!(79228162514.264337593543950335));
+    assert_eq!(ether.to_unit("wei")?, wei);
+    Ok(())
+}
+
+#[test]
+fn test_fractional_base_units_rejected() {
+    // Half a satoshi.
+    assert!(matches!(
+        FinMoney::from_unit(dec!(0.5), "sat", FinMoneyCurrency::BTC),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+
+    let too_precise = FinMoney::new(dec!(0.000000015), FinMoneyCurrency::BTC);
+    assert!(matches!(
+        too_precise.to_unit("sat"),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+}
+
+#[test]
+fn test_overflow_rejected() {
+    let huge = FinMoney::new(dec!(100000000000000), FinMoneyCurrency::ETH);
+    assert_eq!(huge.to_unit("wei"), Err(FinMoneyError::ArithmeticOverflow));
+}
+
+#[test]
+fn test_unknown_unit() -> Result<(), FinMoneyError> {
+    let usd = FinMoney::new(dec!(1), FinMoneyCurrency::USD);
+    assert!(matches!(
+        usd.to_unit("sat"),
+        Err(FinMoneyError::InvalidUnit(_))
+    ));
+    assert!(matches!(
+        FinMoney::from_unit(dec!(1), "SAT", FinMoneyCurrency::BTC),
+        Err(FinMoneyError::InvalidUnit(_))
+    ));
+
+    // Registered units are only found through the registry.
+    let (token, registry) = token_registry(2, dec!(0.01))?;
+    assert!(matches!(
+        FinMoney::from_unit(dec!(1), "unit", token),
+        Err(FinMoneyError::InvalidUnit(_))
+    ));
+    assert!(FinMoney::from_unit_with(dec!(1), "unit", token, &registry).is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_registry_units() -> Result<(), FinMoneyError> {
+    let (token, registry) = token_registry(2, dec!(0.01))?;
+    let amount = FinMoney::from_unit_with(dec!(1999), "unit", token, &registry)?;
+    assert_eq!(amount.get_amount(), dec!(19.99));
+    assert_eq!(amount.to_unit_with("unit", &registry)?, dec!(1999));
+
+    // Units are scoped to the registry.
+    let other = FinMoneyCurrencyRegistry::with_currencies([token])?;
+    assert!(other.get_unit(&token, "unit").is_none());
+
+    let unregistered = FinMoneyCurrencyRegistry::new();
+    assert_eq!(
+        unregistered.set_unit(token, FinDenomination::new("unit", dec!(0.01))?),
+        Err(FinMoneyError::UnknownCurrency("TOK".to_string()))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_registry_unit_overrides_builtin() -> Result<(), FinMoneyError> {
+    let registry = FinMoneyCurrencyRegistry::with_currencies([FinMoneyCurrency::BTC])?;
+    assert_eq!(
+        registry.get_unit(&FinMoneyCurrency::BTC, "sat"),
+        FinDenomination::builtin(&FinMoneyCurrency::BTC, "sat")
+    );
+
+    registry.set_unit(
+        FinMoneyCurrency::BTC,
+        FinDenomination::new("sat", dec!(0.0000001))?,
+    )?;
+    registry.set_unit(
+        FinMoneyCurrency::BTC,
+        FinDenomination::new("msat", dec!(0.00000000001))?,
+    )?;
+    assert_eq!(
+        registry
+            .get_unit(&FinMoneyCurrency::BTC, "sat")
+            .map(|unit| unit.get_factor()),
+        Some(dec!(0.0000001))
+    );
+    let names: Vec<_> = registry
+        .get_units(&FinMoneyCurrency::BTC)
+        .iter()
+        .map(|unit| unit.get_name().to_string())
+        .collect();
+    assert_eq!(names, ["msat", "sat", "bit", "mBTC"]);
+    Ok(())
+}
+
+#[test]
+fn test_non_power_of_ten_factors_are_exact() -> Result<(), FinMoneyError> {
+    let (token, registry) = token_registry(0, dec!(0.3))?;
+
+    assert_eq!(
+        FinMoney::from_unit_with(dec!(10), "unit", token, &registry)?.get_amount(),
+        dec!(3)
+    );
+    assert_eq!(
+        FinMoney::new(dec!(3), token).to_unit_with("unit", &registry)?,
+        dec!(10)
+    );
+    let (tenths, tenths_registry) = token_registry(1, dec!(0.3))?;
+    assert_eq!(
+        FinMoney::new(dec!(0.9), tenths).to_unit_with("unit", &tenths_registry)?,
+        dec!(3)
+    );
+
+    // 1 / 0.3 does not terminate.
+    assert!(matches!(
+        FinMoney::new(dec!(1), token).to_unit_with("unit", &registry),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+    // 5 * 0.3 = 1.5 is not a whole base unit.
+    assert!(matches!(
+        FinMoney::from_unit_with(dec!(5), "unit", token, &registry),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+    // Exactly 23768448754279301278063185100.5, which Decimal would round.
+    assert!(matches!(
+        FinMoney::from_unit_with(Decimal::MAX, "unit", token, &registry),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+    // Exactly 2376844875427930127806318510.05, which Decimal would round to a whole number.
+    assert!(matches!(
+        FinMoney::from_unit_with(
+            dec!(7922816251426433759354395033.5),
+            "unit",
+            token,
+            &registry
+        ),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_large_mantissa_with_quarter_factor() -> Result<(), FinMoneyError> {
+    let (token, registry) = token_registry(0, dec!(0.25))?;
+    let exact =
+        FinMoney::from_unit_with(dec!(4000000000000000000000000000), "unit", token, &registry)?;
+    assert_eq!(exact.get_amount(), dec!(1000000000000000000000000000));
+    assert_eq!(
+        exact.to_unit_with("unit", &registry)?,
+        dec!(4000000000000000000000000000)
+    );
+
+    // Decimal::MAX / 4 needs more digits than Decimal has.
+    assert!(matches!(
+        FinMoney::from_unit_with(Decimal::MAX, "unit", token, &registry),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_unit_definition() {
+    assert!(matches!(
+        FinDenomination::new("", dec!(1)),
+        Err(FinMoneyError::InvalidUnit(_))
+    ));
+    assert!(matches!(
+        FinDenomination::new("negative", dec!(-1)),
+        Err(FinMoneyError::InvalidUnit(_))
+    ));
+}
@@ -0,0 +1,113 @@
+//! Tests for exchange rates and currency conversion.
+
+use finmoney::{
+    FinExchangeRate, FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy, iso4217,
+};
+use rust_decimal_macros::dec;
+
+#[test]
+fn test_convert_rounds_to_quote_precision() -> Result<(), FinMoneyError> {
+    let usd_jpy = FinExchangeRate::new(iso4217::USD, iso4217::JPY, dec!(151.37))?;
+    let dollars = FinMoney::new(dec!(10.25), iso4217::USD);
+
+    // 10.25 * 151.37 = 1551.5425
+    let yen = usd_jpy.convert(&dollars, FinMoneyRoundingStrategy::MidpointNearestEven)?;
+    assert_eq!(yen, FinMoney::new(dec!(1552), iso4217::JPY));
+
+    let floor = usd_jpy.convert(&dollars, FinMoneyRoundingStrategy::ToZero)?;
+    assert_eq!(floor.get_amount(), dec!(1551));
+    Ok(())
+}
+
+#[test]
+fn test_convert_checks_source_currency() -> Result<(), FinMoneyError> {
+    let eur_usd = FinExchangeRate::new(FinMoneyCurrency::EUR, FinMoneyCurrency::USD, dec!(1.08))?;
+    let dollars = FinMoney::new(dec!(1), FinMoneyCurrency::USD);
+
+    assert_eq!(
+        eur_usd.convert(&dollars, FinMoneyRoundingStrategy::default()),
+        Err(FinMoneyError::CurrencyMismatch {
+            expected: "EUR".to_string(),
+            actual: "USD".to_string(),
+            index: None,
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_rejects_non_positive_rates() {
+    assert_eq!(
+        FinExchangeRate::new(FinMoneyCurrency::EUR, FinMoneyCurrency::USD, dec!(0)),
+        Err(FinMoneyError::InvalidExchangeRate(dec!(0)))
+    );
+    assert_eq!(
+        FinExchangeRate::new(FinMoneyCurrency::EUR, FinMoneyCurrency::USD, dec!(-1.08)),
+        Err(FinMoneyError::InvalidExchangeRate(dec!(-1.08)))
+    );
+}
+
+#[test]
+fn test_same_currency_rate_must_be_one() -> Result<(), FinMoneyError> {
+    assert_eq!(
+        FinExchangeRate::new(iso4217::USD, iso4217::USD, dec!(1.1)),
+        Err(FinMoneyError::InvalidExchangeRate(dec!(1.1)))
+    );
+    let identity = FinExchangeRate::new(iso4217::USD, iso4217::USD, dec!(1))?;
+    let dollars = FinMoney::new(dec!(10.25), iso4217::USD);
+    assert_eq!(
+        identity.convert(&dollars, FinMoneyRoundingStrategy::default())?,
+        dollars
+    );
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_deserialize_validates() -> Result<(), Box<dyn std::error::Error>> {
+    let eur_usd = FinExchangeRate::new(iso4217::EUR, iso4217::USD, dec!(1.0850))?;
+    let json = serde_json::to_value(eur_usd)?;
+    assert_eq!(
+        serde_json::from_value::<FinExchangeRate>(json.clone())?,
+        eur_usd
+    );
+
+    let mut zero = json.clone();
+    zero["rate"] = "0".into();
+    assert!(serde_json::from_value::<FinExchangeRate>(zero).is_err());
+
+    let mut same = json;
+    same["quote"] = serde_json::to_value(iso4217::EUR)?;
+    assert!(serde_json::from_value::<FinExchangeRate>(same).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_inverse() -> Result<(), FinMoneyError> {
+    let usd_jpy = FinExchangeRate::new(iso4217::USD, iso4217::JPY, dec!(150))?;
+
+    let jpy_usd = usd_jpy.inverse(8, FinMoneyRoundingStrategy::MidpointNearestEven)?;
+    assert_eq!(jpy_usd.get_base(), iso4217::JPY);
+    assert_eq!(jpy_usd.get_quote(), iso4217::USD);
+    assert_eq!(jpy_usd.get_rate(), dec!(0.00666667));
+
+    let truncated = usd_jpy.inverse(4, FinMoneyRoundingStrategy::ToZero)?;
+    assert_eq!(truncated.get_rate(), dec!(0.0066));
+
+    assert_eq!(
+        usd_jpy.inverse(2, FinMoneyRoundingStrategy::ToZero),
+        Err(FinMoneyError::InvalidExchangeRate(dec!(0)))
+    );
+    assert_eq!(
+        usd_jpy.inverse(29, FinMoneyRoundingStrategy::default()),
+        Err(FinMoneyError::InvalidPrecision(29))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_display() -> Result<(), FinMoneyError> {
+    let eur_usd = FinExchangeRate::new(FinMoneyCurrency::EUR, FinMoneyCurrency::USD, dec!(1.0850))?;
+    assert_eq!(eur_usd.to_string(), "EUR/USD 1.0850");
+    Ok(())
+}
@@ -0,0 +1,123 @@
+//! Tests for FX forward pricing.
+
+use finmoney::{
+    FinCurrencyPair, FinDate, FinDayCount, FinExchangeRate, FinInterestRate, FinMoney,
+    FinMoneyError, FinMoneyRoundingStrategy, FinPairConventions, iso4217,
+};
+use rust_decimal_macros::dec;
+
+fn date(s: &str) -> FinDate {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_day_counts() {
+    let (start, end) = (date("2024-01-31"), date("2024-03-31"));
+
+    assert_eq!(
+        FinDayCount::Act360.year_fraction(start, end),
+        dec!(60) / dec!(360)
+    );
+    assert_eq!(
+        FinDayCount::Act365Fixed.year_fraction(start, end),
+        dec!(60) / dec!(365)
+    );
+    assert_eq!(
+        FinDayCount::Thirty360.year_fraction(start, end),
+        dec!(60) / dec!(360)
+    );
+
+    // Feb 28 -> Mar 31: 31 actual days, 33 days on a 30/360 basis.
+    let (start, end) = (date("2023-02-28"), date("2023-03-31"));
+    assert_eq!(FinDayCount::Act360.days_between(start, end), 31);
+    assert_eq!(FinDayCount::Thirty360.days_between(start, end), 33);
+    assert_eq!(FinDayCount::Act365Fixed.year_basis(), 365);
+    assert_eq!(
+        FinDayCount::Thirty360.year_fraction(date("2023-01-01"), date("2024-01-01")),
+        dec!(1)
+    );
+}
+
+#[test]
+fn test_forward_points_scale_by_pip() -> Result<(), FinMoneyError> {
+    let conventions = FinPairConventions::market();
+
+    let eur_usd = FinExchangeRate::new(iso4217::EUR, iso4217::USD, dec!(1.0850))?;
+    let pair: FinCurrencyPair = "EURUSD".parse()?;
+    let forward = eur_usd.forward_from_points(dec!(25.5), &conventions.get_convention(pair))?;
+    assert_eq!(forward.get_rate(), dec!(1.08755));
+
+    let usd_jpy = FinExchangeRate::new(iso4217::USD, iso4217::JPY, dec!(150))?;
+    let pair: FinCurrencyPair = "USDJPY".parse()?;
+    let forward = usd_jpy.forward_from_points(dec!(-120), &conventions.get_convention(pair))?;
+    assert_eq!(forward.get_rate(), dec!(148.80));
+    Ok(())
+}
+
+#[test]
+fn test_forward_points_must_leave_positive_rate() -> Result<(), FinMoneyError> {
+    let conventions = FinPairConventions::market();
+    let spot = FinExchangeRate::new(iso4217::EUR, iso4217::USD, dec!(0.0010))?;
+    let pair: FinCurrencyPair = "EURUSD".parse()?;
+
+    assert!(matches!(
+        spot.forward_from_points(dec!(-10), &conventions.get_convention(pair)),
+        Err(FinMoneyError::InvalidExchangeRate(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_covered_interest_parity() -> Result<(), FinMoneyError> {
+    let spot = FinExchangeRate::new(iso4217::GBP, iso4217::USD, dec!(1.25))?;
+    let gbp = FinInterestRate::new(dec!(0.05), FinDayCount::Act365Fixed);
+    let usd = FinInterestRate::new(dec!(0.05), FinDayCount::Act360);
+    assert_eq!(gbp.get_rate(), dec!(0.05));
+    assert_eq!(gbp.get_day_count(), FinDayCount::Act365Fixed);
+
+    // One year: 1.25 * (1 + 0.05 * 366/360) / (1 + 0.05 * 366/365)
+    let forward = spot.forward_from_interest(gbp, usd, date("2024-01-01"), date("2025-01-01"))?;
+    assert_eq!(forward.get_rate().round_dp(6), dec!(1.250829));
+
+    // Same rates and conventions leave spot unchanged.
+    let forward = spot.forward_from_interest(usd, usd, date("2024-01-01"), date("2025-01-01"))?;
+    assert_eq!(forward.get_rate(), dec!(1.25));
+
+    let same_day = spot.forward_from_interest(gbp, usd, date("2024-01-01"), date("2024-01-01"))?;
+    assert_eq!(same_day.get_rate(), dec!(1.25));
+    Ok(())
+}
+
+#[test]
+fn test_forward_amount_rounds_to_quote_precision() -> Result<(), FinMoneyError> {
+    let spot = FinExchangeRate::new(iso4217::EUR, iso4217::USD, dec!(1.08))?;
+    let forward = spot.forward_from_interest(
+        FinInterestRate::new(dec!(0.04), FinDayCount::Act360),
+        FinInterestRate::new(dec!(0.05), FinDayCount::Act360),
+        date("2024-01-02"),
+        date("2024-07-01"),
+    )?;
+
+    let notional = FinMoney::new(dec!(1000000), iso4217::EUR);
+    let amount = forward.convert(&notional, FinMoneyRoundingStrategy::MidpointNearestEven)?;
+    assert_eq!(amount, FinMoney::new(dec!(1085322.95), iso4217::USD));
+    Ok(())
+}
+
+#[test]
+fn test_interest_errors() -> Result<(), FinMoneyError> {
+    let spot = FinExchangeRate::new(iso4217::EUR, iso4217::USD, dec!(1.08))?;
+    let rate = FinInterestRate::new(dec!(0.05), FinDayCount::Act360);
+
+    assert!(matches!(
+        spot.forward_from_interest(rate, rate, date("2024-07-01"), date("2024-01-01")),
+        Err(FinMoneyError::InvalidDate(_))
+    ));
+
+    let wipeout = FinInterestRate::new(dec!(-1), FinDayCount::Thirty360);
+    assert_eq!(
+        spot.forward_from_interest(wipeout, rate, date("2024-01-01"), date("2025-01-01")),
+        Err(FinMoneyError::DivisionByZero)
+    );
+    Ok(())
+}
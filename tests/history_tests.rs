@@ -0,0 +1,213 @@
+//! Tests for dates and historical exchange rates.
+
+use finmoney::{
+    FinAsOf, FinDate, FinExchangeRate, FinMoney, FinMoneyError, FinMoneyRoundingStrategy,
+    FinRateGap, FinRateHistory, FinTriangulation, RateProvider, iso4217,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn date(s: &str) -> FinDate {
+    s.parse().unwrap()
+}
+
+fn eur_usd(rate: Decimal) -> Result<FinExchangeRate, FinMoneyError> {
+    FinExchangeRate::new(iso4217::EUR, iso4217::USD, rate)
+}
+
+fn history() -> Result<FinRateHistory, FinMoneyError> {
+    let mut history = FinRateHistory::new();
+    history.insert(date("2024-01-01"), eur_usd(dec!(1.10))?);
+    history.insert(date("2024-01-05"), eur_usd(dec!(1.14))?);
+    history.insert(date("2024-01-06"), eur_usd(dec!(1.13))?);
+    Ok(history)
+}
+
+#[test]
+fn test_date_roundtrip() -> Result<(), FinMoneyError> {
+    let leap = FinDate::new(2024, 2, 29)?;
+    assert_eq!(leap.to_string(), "2024-02-29");
+    assert_eq!("2024-02-29".parse::<FinDate>()?, leap);
+    assert!(leap.is_leap_year());
+
+    assert_eq!(FinDate::new(1970, 1, 1)?.to_days_since_epoch(), 0);
+    assert_eq!(
+        FinDate::from_days_since_epoch(19_782)?,
+        FinDate::new(2024, 2, 29)?
+    );
+    assert_eq!(
+        FinDate::from_days_since_epoch(-1)?,
+        FinDate::new(1969, 12, 31)?
+    );
+    assert_eq!(leap.add_days(1)?, FinDate::new(2024, 3, 1)?);
+    assert_eq!(
+        FinDate::new(2023, 1, 1)?.days_until(FinDate::new(2024, 1, 1)?),
+        365
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_dates() {
+    for input in [
+        "2023-02-29",
+        "2024-13-01",
+        "2024-1-01",
+        "0000-01-01",
+        "2024/01/01",
+        "+024-01-01",
+    ] {
+        assert!(
+            matches!(input.parse::<FinDate>(), Err(FinMoneyError::InvalidDate(_))),
+            "{input}"
+        );
+    }
+    assert!(FinDate::new(9999, 12, 31).unwrap().add_days(1).is_err());
+}
+
+#[test]
+fn test_as_of_modes() -> Result<(), FinMoneyError> {
+    let history = history()?;
+    let (eur, usd) = (iso4217::EUR, iso4217::USD);
+
+    let rate = |at, as_of| {
+        history
+            .get_rate(eur, usd, date(at), as_of)
+            .map(|r| r.get_rate())
+    };
+
+    assert_eq!(rate("2024-01-05", FinAsOf::Exact)?, dec!(1.14));
+    assert!(matches!(
+        rate("2024-01-03", FinAsOf::Exact),
+        Err(FinMoneyError::MissingExchangeRate { .. })
+    ));
+
+    assert_eq!(rate("2024-01-03", FinAsOf::Previous)?, dec!(1.10));
+    assert_eq!(rate("2024-02-01", FinAsOf::Previous)?, dec!(1.13));
+    assert!(rate("2023-12-31", FinAsOf::Previous).is_err());
+
+    assert_eq!(rate("2024-01-02", FinAsOf::Interpolated)?, dec!(1.11));
+    assert_eq!(rate("2024-01-06", FinAsOf::Interpolated)?, dec!(1.13));
+    assert!(rate("2024-01-07", FinAsOf::Interpolated).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_inverse_lookup_and_conversion() -> Result<(), FinMoneyError> {
+    let history = history()?;
+    assert!(!history.is_empty());
+    assert_eq!(history.pair_len(iso4217::EUR, iso4217::USD), 3);
+    // Observations are counted per direction.
+    assert_eq!(history.pair_len(iso4217::USD, iso4217::EUR), 0);
+    let dollars = FinMoney::new(dec!(114), iso4217::USD);
+
+    let euros = history.convert(
+        &dollars,
+        iso4217::EUR,
+        date("2024-01-05"),
+        FinAsOf::Previous,
+        FinMoneyRoundingStrategy::MidpointNearestEven,
+    )?;
+    assert_eq!(euros, FinMoney::new(dec!(100), iso4217::EUR));
+    Ok(())
+}
+
+#[test]
+fn test_timestamp_keys() -> Result<(), FinMoneyError> {
+    let mut history: FinRateHistory<i64> = FinRateHistory::new();
+    history.insert(1_700_000_000, eur_usd(dec!(1.08))?);
+    history.insert(1_700_000_060, eur_usd(dec!(1.09))?);
+
+    let mid = history.get_rate(
+        iso4217::EUR,
+        iso4217::USD,
+        1_700_000_030,
+        FinAsOf::Interpolated,
+    )?;
+    assert_eq!(mid.get_rate(), dec!(1.085));
+    assert_eq!(history.gaps(iso4217::EUR, iso4217::USD, 59).len(), 1);
+    assert!(history.gaps(iso4217::EUR, iso4217::USD, 60).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_far_apart_timestamp_keys() -> Result<(), FinMoneyError> {
+    let mut history: FinRateHistory<i64> = FinRateHistory::new();
+    history.insert(i64::MIN, eur_usd(dec!(1))?);
+    history.insert(i64::MAX, eur_usd(dec!(3))?);
+
+    let mid = history.get_rate(iso4217::EUR, iso4217::USD, 0, FinAsOf::Interpolated)?;
+    assert_eq!(mid.get_rate().round_dp(10), dec!(2));
+
+    let gaps = history.gaps(iso4217::EUR, iso4217::USD, i64::MAX);
+    assert_eq!(
+        gaps,
+        vec![FinRateGap {
+            from: i64::MIN,
+            to: i64::MAX,
+        }]
+    );
+    assert_eq!(history.gaps(iso4217::EUR, iso4217::USD, i64::MIN).len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_snapshot_triangulates() -> Result<(), FinMoneyError> {
+    let mut history = history()?;
+    history.insert(
+        date("2024-01-04"),
+        FinExchangeRate::new(iso4217::USD, iso4217::JPY, dec!(145))?,
+    );
+
+    let table = history.snapshot(
+        date("2024-01-05"),
+        FinAsOf::Previous,
+        FinTriangulation::Pivot(iso4217::USD),
+    );
+    let eur_jpy = table.get_rate(iso4217::EUR, iso4217::JPY)?;
+    assert_eq!(eur_jpy.get_rate().get_rate(), dec!(165.30));
+
+    let early = history.snapshot(
+        date("2024-01-01"),
+        FinAsOf::Previous,
+        FinTriangulation::Direct,
+    );
+    assert_eq!(early.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_gaps() -> Result<(), FinMoneyError> {
+    let history = history()?;
+    assert_eq!(
+        history.gaps(iso4217::EUR, iso4217::USD, 1),
+        vec![FinRateGap {
+            from: date("2024-01-01"),
+            to: date("2024-01-05"),
+        }]
+    );
+    assert!(history.gaps(iso4217::EUR, iso4217::USD, 4).is_empty());
+    assert!(history.gaps(iso4217::USD, iso4217::EUR, 1).is_empty());
+    Ok(())
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn test_load_csv() -> Result<(), FinMoneyError> {
+    let mut history: FinRateHistory = FinRateHistory::new();
+    let csv = "at,rate\n2024-01-01,1.1037\n2024-01-02,1.0942\n";
+    assert_eq!(history.load_csv(iso4217::EUR, iso4217::USD, csv), Ok(2));
+    assert_eq!(history.pair_len(iso4217::EUR, iso4217::USD), 2);
+
+    let bad = "at,rate\n2024-01-03,1.09\n2024-02-30,1.09\n2024-01-05,0\n";
+    let errors = history
+        .load_csv(iso4217::EUR, iso4217::USD, bad)
+        .unwrap_err();
+    let lines: Vec<_> = errors.iter().map(|e| e.line).collect();
+    assert_eq!(lines, vec![Some(3), Some(4)]);
+    assert!(matches!(errors[0].error, FinMoneyError::InvalidConfig(_)));
+    assert_eq!(errors[1].error, FinMoneyError::InvalidExchangeRate(dec!(0)));
+    // Nothing from the failed load was inserted.
+    assert_eq!(history.pair_len(iso4217::EUR, iso4217::USD), 2);
+    Ok(())
+}
@@ -0,0 +1,99 @@
+//! Tests for the built-in ISO 4217 currency table.
+
+use finmoney::{
+    FinCurrencyPair, FinMoney, FinMoneyCurrency, FinMoneyRoundingStrategy, FinPairConventions,
+    iso4217,
+};
+use rust_decimal_macros::dec;
+
+#[test]
+fn test_iso_constants() {
+    let jpy = iso4217::JPY;
+    assert_eq!(jpy.get_id(), 392);
+    assert_eq!(jpy.get_code(), "JPY");
+    assert_eq!(jpy.get_name(), Some("Yen"));
+    assert_eq!(jpy.get_precision(), 0);
+
+    assert_eq!(iso4217::KWD.get_precision(), 3);
+    assert_eq!(iso4217::CLF.get_precision(), 4);
+    assert_eq!(iso4217::USD.get_id(), 840);
+    assert_eq!(iso4217::EUR.get_name(), Some("Euro"));
+}
+
+#[test]
+fn test_lookup_by_alpha() {
+    assert_eq!(FinMoneyCurrency::from_iso_alpha("JPY"), Some(iso4217::JPY));
+    assert_eq!(FinMoneyCurrency::from_iso_alpha("gbp"), Some(iso4217::GBP));
+    assert_eq!(FinMoneyCurrency::from_iso_alpha("AED"), Some(iso4217::AED));
+    assert_eq!(FinMoneyCurrency::from_iso_alpha("ZWG"), Some(iso4217::ZWG));
+    assert_eq!(FinMoneyCurrency::from_iso_alpha("XCG"), Some(iso4217::XCG));
+    // Replaced by the Caribbean Guilder (XCG).
+    assert_eq!(FinMoneyCurrency::from_iso_alpha("ANG"), None);
+    assert_eq!(FinMoneyCurrency::from_iso_alpha("BTC"), None);
+    assert_eq!(FinMoneyCurrency::from_iso_alpha(""), None);
+}
+
+#[test]
+fn test_lookup_by_numeric() {
+    assert_eq!(FinMoneyCurrency::from_iso_numeric(392), Some(iso4217::JPY));
+    assert_eq!(FinMoneyCurrency::from_iso_numeric(8), Some(iso4217::ALL));
+    assert_eq!(FinMoneyCurrency::from_iso_numeric(978), Some(iso4217::EUR));
+    assert_eq!(FinMoneyCurrency::from_iso_numeric(532), Some(iso4217::XCG));
+    assert_eq!(FinMoneyCurrency::from_iso_numeric(999), None);
+}
+
+#[test]
+fn test_table_is_consistent() {
+    let table = iso4217::CURRENCIES;
+    assert!(table.len() > 150);
+
+    for pair in table.windows(2) {
+        assert!(pair[0].get_code() < pair[1].get_code());
+    }
+
+    for currency in table {
+        assert_eq!(currency.get_code().len(), 3);
+        assert!(currency.get_precision() <= 4);
+        assert!(currency.get_name().is_some());
+        assert_eq!(
+            FinMoneyCurrency::from_iso_alpha(currency.get_code()),
+            Some(*currency)
+        );
+        let numeric = u16::try_from(currency.get_id()).unwrap();
+        assert_eq!(FinMoneyCurrency::from_iso_numeric(numeric), Some(*currency));
+    }
+}
+
+#[test]
+fn test_iso_currency_rounding() {
+    let price = FinMoney::new_with_precision(
+        dec!(1234.567),
+        iso4217::JPY,
+        FinMoneyRoundingStrategy::MidpointNearestEven,
+    );
+    assert_eq!(price.get_amount(), dec!(1235));
+
+    let price = FinMoney::new_with_precision(
+        dec!(1.23456),
+        iso4217::KWD,
+        FinMoneyRoundingStrategy::MidpointNearestEven,
+    );
+    assert_eq!(price.get_amount(), dec!(1.235));
+}
+
+#[test]
+fn test_legacy_constants_interoperate_with_iso_entries() -> Result<(), finmoney::FinMoneyError> {
+    let legacy = FinMoney::new(dec!(1), FinMoneyCurrency::USD);
+    let iso = FinMoney::new(dec!(2), iso4217::USD);
+    assert_eq!(legacy.checked_add(iso)?.get_amount(), dec!(3));
+    assert_eq!(
+        FinMoneyCurrency::from_iso_alpha("EUR"),
+        Some(FinMoneyCurrency::EUR)
+    );
+
+    let conventions = FinPairConventions::market();
+    let pair = FinCurrencyPair::new(FinMoneyCurrency::USD, FinMoneyCurrency::EUR)?;
+    assert_eq!(conventions.normalize(pair).to_string(), "EUR/USD");
+    assert!(conventions.is_market_order(pair.inverse()));
+    Ok(())
+}
@@ -0,0 +1,100 @@
+//! Tests for iterator aggregation of FinMoney values.
+
+use finmoney::{FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyIteratorExt};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn usd(amount: Decimal) -> FinMoney {
+    FinMoney::new(amount, FinMoneyCurrency::USD)
+}
+
+#[test]
+fn test_try_sum() -> Result<(), FinMoneyError> {
+    let values = vec![usd(dec!(10.50)), usd(dec!(2.25)), usd(dec!(-0.75))];
+
+    assert_eq!(values.iter().try_sum()?, Some(usd(dec!(12.00))));
+    assert_eq!(values.into_iter().try_sum()?, Some(usd(dec!(12.00))));
+
+    let empty: Vec<FinMoney> = Vec::new();
+    assert_eq!(empty.iter().try_sum()?, None);
+    Ok(())
+}
+
+#[test]
+fn test_try_sum_in() -> Result<(), FinMoneyError> {
+    let values = [usd(dec!(1)), usd(dec!(2))];
+    assert_eq!(
+        values.iter().try_sum_in(FinMoneyCurrency::USD)?,
+        usd(dec!(3))
+    );
+
+    let empty: [FinMoney; 0] = [];
+    let zero = empty.iter().try_sum_in(FinMoneyCurrency::EUR)?;
+    assert!(zero.is_zero());
+    assert_eq!(zero.get_currency(), FinMoneyCurrency::EUR);
+
+    // The first element is checked against the requested currency as well
+    let err = values.iter().try_sum_in(FinMoneyCurrency::EUR).unwrap_err();
+    assert_eq!(
+        err,
+        FinMoneyError::CurrencyMismatch {
+            expected: "EUR".to_string(),
+            actual: "USD".to_string(),
+            index: Some(0),
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn test_mismatch_reports_index() {
+    let values = [
+        usd(dec!(1)),
+        usd(dec!(2)),
+        FinMoney::new(dec!(3), FinMoneyCurrency::EUR),
+    ];
+
+    for result in [
+        values.iter().try_sum(),
+        values.iter().try_min(),
+        values.iter().try_max(),
+    ] {
+        let err = result.unwrap_err();
+        assert_eq!(
+            err,
+            FinMoneyError::CurrencyMismatch {
+                expected: "USD".to_string(),
+                actual: "EUR".to_string(),
+                index: Some(2),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Currency mismatch at index 2: expected USD, got EUR"
+        );
+    }
+}
+
+#[test]
+fn test_try_min_max() -> Result<(), FinMoneyError> {
+    let values = [usd(dec!(5)), usd(dec!(-2)), usd(dec!(7)), usd(dec!(7.0))];
+
+    assert_eq!(values.iter().try_min()?, Some(usd(dec!(-2))));
+    // Ties resolve like `Iterator::max`: the last one wins
+    let max = values.iter().try_max()?.unwrap();
+    assert_eq!(max.to_string(), "7.0 USD");
+
+    let empty: Vec<FinMoney> = Vec::new();
+    assert_eq!(empty.iter().try_min()?, None);
+    assert_eq!(empty.iter().try_max()?, None);
+    Ok(())
+}
+
+#[test]
+fn test_try_sum_overflow() {
+    let values = [usd(Decimal::MAX), usd(Decimal::ONE)];
+    assert_eq!(
+        values.iter().try_sum(),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+}
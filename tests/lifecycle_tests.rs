@@ -0,0 +1,211 @@
+//! Tests for currency lifecycles and redenomination.
+
+use finmoney::{
+    FinCurrencyLifecycle, FinDate, FinMoney, FinMoneyCurrency, FinMoneyCurrencyRegistry,
+    FinMoneyError, FinMoneyRoundingStrategy, iso4217,
+};
+use rust_decimal_macros::dec;
+
+fn date(s: &str) -> FinDate {
+    s.parse().unwrap()
+}
+
+struct Venezuela {
+    registry: FinMoneyCurrencyRegistry,
+    veb: FinMoneyCurrency,
+    vef: FinMoneyCurrency,
+}
+
+fn venezuela() -> Result<Venezuela, FinMoneyError> {
+    let veb = FinMoneyCurrency::new(862, "VEB", None, 2)?;
+    let vef = FinMoneyCurrency::new(937, "VEF", None, 2)?;
+    let registry = FinMoneyCurrencyRegistry::with_currencies([veb, vef, iso4217::VES])?;
+
+    registry.set_lifecycle(
+        veb,
+        FinCurrencyLifecycle::new()
+            .with_active_to(date("2007-12-31"))
+            .with_successor(vef, dec!(1000))?,
+    )?;
+    registry.set_lifecycle(
+        vef,
+        FinCurrencyLifecycle::new()
+            .with_active_from(date("2008-01-01"))
+            .with_active_to(date("2018-08-19"))
+            .with_successor(iso4217::VES, dec!(100000))?,
+    )?;
+    registry.set_lifecycle(
+        iso4217::VES,
+        FinCurrencyLifecycle::new().with_active_from(date("2018-08-20")),
+    )?;
+    Ok(Venezuela { registry, veb, vef })
+}
+
+#[test]
+fn test_active_dates() -> Result<(), FinMoneyError> {
+    let v = venezuela()?;
+
+    assert!(v.registry.is_active_on(&v.vef, date("2008-01-01")));
+    assert!(v.registry.is_active_on(&v.vef, date("2018-08-19")));
+    assert!(!v.registry.is_active_on(&v.vef, date("2018-08-20")));
+    assert!(!v.registry.is_active_on(&v.vef, date("2007-12-31")));
+    assert!(v.registry.is_active_on(&iso4217::VES, date("2030-01-01")));
+    assert!(v.registry.is_active_on(&v.veb, date("1990-01-01")));
+    // No lifecycle: always active.
+    assert!(v.registry.is_active_on(&iso4217::USD, date("1990-01-01")));
+
+    let lifecycle = v.registry.get_lifecycle(&v.vef).unwrap();
+    assert_eq!(
+        lifecycle.get_successor(),
+        Some((iso4217::VES, dec!(100000)))
+    );
+    assert_eq!(lifecycle.get_active_from(), Some(date("2008-01-01")));
+    Ok(())
+}
+
+#[test]
+fn test_redenominate() -> Result<(), FinMoneyError> {
+    let v = venezuela()?;
+    let strategy = FinMoneyRoundingStrategy::MidpointAwayFromZero;
+
+    let old = FinMoney::new(dec!(12345678.90), v.vef);
+    let new = old.redenominate(&v.registry, strategy)?;
+    // 123.456789 rounded to the successor's 2 decimals
+    assert_eq!(new, FinMoney::new(dec!(123.46), iso4217::VES));
+
+    // Follow a chain one step at a time.
+    let ancient = FinMoney::new(dec!(1000000000), v.veb);
+    let current = ancient
+        .redenominate(&v.registry, strategy)?
+        .redenominate(&v.registry, strategy)?;
+    assert_eq!(current, FinMoney::new(dec!(10), iso4217::VES));
+    Ok(())
+}
+
+#[test]
+fn test_redenominate_errors() -> Result<(), FinMoneyError> {
+    let v = venezuela()?;
+    let strategy = FinMoneyRoundingStrategy::default();
+
+    assert_eq!(
+        FinMoney::new(dec!(1), iso4217::VES).redenominate(&v.registry, strategy),
+        Err(FinMoneyError::NoSuccessorCurrency("VES".to_string()))
+    );
+    assert_eq!(
+        FinMoney::new(dec!(1), iso4217::USD).redenominate(&v.registry, strategy),
+        Err(FinMoneyError::UnknownCurrency("USD".to_string()))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_lifecycle_validation() -> Result<(), FinMoneyError> {
+    let v = venezuela()?;
+
+    for factor in [dec!(0), dec!(-2)] {
+        assert_eq!(
+            FinCurrencyLifecycle::new().with_successor(iso4217::VES, factor),
+            Err(FinMoneyError::InvalidExchangeRate(factor))
+        );
+    }
+    assert!(matches!(
+        v.registry.set_lifecycle(
+            v.veb,
+            FinCurrencyLifecycle::new()
+                .with_active_from(date("2000-01-01"))
+                .with_active_to(date("1999-12-31")),
+        ),
+        Err(FinMoneyError::InvalidDate(_))
+    ));
+    assert!(matches!(
+        v.registry.set_lifecycle(
+            v.veb,
+            FinCurrencyLifecycle::new().with_successor(iso4217::USD, dec!(1))?
+        ),
+        Err(FinMoneyError::UnknownCurrency(_))
+    ));
+    assert!(matches!(
+        v.registry.set_lifecycle(
+            v.veb,
+            FinCurrencyLifecycle::new().with_successor(v.veb, dec!(1))?
+        ),
+        Err(FinMoneyError::InvalidCurrencyPair(_))
+    ));
+    assert!(matches!(
+        v.registry
+            .set_lifecycle(iso4217::EUR, FinCurrencyLifecycle::new()),
+        Err(FinMoneyError::UnknownCurrency(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_successor_cycles_are_rejected() -> Result<(), FinMoneyError> {
+    let registry = FinMoneyCurrencyRegistry::with_currencies([iso4217::USD, iso4217::EUR])?;
+    registry.set_lifecycle(
+        iso4217::USD,
+        FinCurrencyLifecycle::new().with_successor(iso4217::EUR, dec!(1))?,
+    )?;
+
+    let back = FinCurrencyLifecycle::new().with_successor(iso4217::USD, dec!(1))?;
+    assert_eq!(
+        registry.set_lifecycle(iso4217::EUR, back),
+        Err(FinMoneyError::InvalidCurrencyPair("EUR/USD".to_string()))
+    );
+    assert_eq!(registry.get_lifecycle(&iso4217::EUR), None);
+
+    // Longer chains are followed too: VEB -> VEF -> VES -> VEB.
+    let v = venezuela()?;
+    assert!(matches!(
+        v.registry.set_lifecycle(
+            iso4217::VES,
+            FinCurrencyLifecycle::new().with_successor(v.veb, dec!(1))?
+        ),
+        Err(FinMoneyError::InvalidCurrencyPair(_))
+    ));
+
+    // Replacing a link that would have closed the cycle is fine.
+    registry.set_lifecycle(iso4217::USD, FinCurrencyLifecycle::new())?;
+    registry.set_lifecycle(iso4217::EUR, back)?;
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_deserialize_validates() -> Result<(), Box<dyn std::error::Error>> {
+    let v = venezuela()?;
+    let lifecycle = FinCurrencyLifecycle::new()
+        .with_active_to(date("2018-08-19"))
+        .with_successor(iso4217::VES, dec!(100000))?;
+    let json = serde_json::to_value(lifecycle)?;
+    assert_eq!(
+        serde_json::from_value::<FinCurrencyLifecycle>(json.clone())?,
+        lifecycle
+    );
+    let plain = serde_json::to_value(FinCurrencyLifecycle::new())?;
+    assert_eq!(
+        serde_json::from_value::<FinCurrencyLifecycle>(plain.clone())?,
+        FinCurrencyLifecycle::new()
+    );
+
+    // A zero factor would fail redenomination, a negative one would flip the sign.
+    for factor in ["0", "-2"] {
+        let mut bad = json.clone();
+        bad["factor"] = factor.into();
+        assert!(serde_json::from_value::<FinCurrencyLifecycle>(bad).is_err());
+    }
+    // A factor without a successor is rejected too.
+    let mut orphan = plain;
+    orphan["factor"] = "1000".into();
+    assert!(serde_json::from_value::<FinCurrencyLifecycle>(orphan).is_err());
+
+    // The registry keeps the validated lifecycle.
+    v.registry
+        .set_lifecycle(v.vef, serde_json::from_value::<FinCurrencyLifecycle>(json)?)?;
+    assert_eq!(
+        FinMoney::new(dec!(500000), v.vef)
+            .redenominate(&v.registry, FinMoneyRoundingStrategy::default())?,
+        FinMoney::new(dec!(5), iso4217::VES)
+    );
+    Ok(())
+}
@@ -0,0 +1,67 @@
+//! Tests for the `currency!` and `money!` macros.
+
+use finmoney::iso4217::{JPY, KWD, USD};
+use finmoney::{
+    FinMoney, FinMoneyCurrency, FinMoneyCurrencyRegistry, FinMoneyError, currency, money,
+};
+use rust_decimal_macros::dec;
+
+const USDT: FinMoneyCurrency = currency!("USDT", 6, "Tether");
+const POINTS: FinMoneyCurrency = currency!(id = 9001, "PTS", 0);
+
+#[test]
+fn test_currency_macro() {
+    assert_eq!(USDT.get_code(), "USDT");
+    assert_eq!(USDT.get_name(), Some("Tether"));
+    assert_eq!(USDT.get_precision(), 6);
+    assert!(USDT.get_id() >= 0);
+
+    assert_eq!(POINTS.get_id(), 9001);
+    assert_eq!(POINTS.get_name(), None);
+    assert_eq!(POINTS.get_precision(), 0);
+}
+
+#[test]
+fn test_currency_macro_ids_are_stable() {
+    let again = currency!("USDT", 6);
+    assert!(USDT.is_same_currency(&again));
+    assert!(!USDT.is_same_currency(&currency!("USDC", 6)));
+}
+
+#[test]
+fn test_colliding_derived_ids_are_rejected_by_the_registry() {
+    // "FIKAZ" and "JBLVE" hash to the same id.
+    const FIKAZ: FinMoneyCurrency = currency!("FIKAZ", 2);
+    const JBLVE: FinMoneyCurrency = currency!("JBLVE", 2);
+    assert_eq!(FIKAZ.get_id(), JBLVE.get_id());
+
+    let registry = FinMoneyCurrencyRegistry::with_currencies([FIKAZ]).unwrap();
+    assert!(matches!(
+        registry.register(JBLVE),
+        Err(FinMoneyError::CurrencyConflict { .. })
+    ));
+}
+
+#[test]
+fn test_money_macro() {
+    const FEE: FinMoney = money!(0.25 USD);
+    assert_eq!(FEE.get_amount(), dec!(0.25));
+    assert_eq!(FEE.get_currency(), USD);
+
+    assert_eq!(money!(10.50 USD).get_amount(), dec!(10.50));
+    assert_eq!(money!(-10.50 USD).get_amount(), dec!(-10.50));
+    assert_eq!(money!(1_000 JPY).get_amount(), dec!(1000));
+    assert_eq!(money!(1.234 KWD).get_amount(), dec!(1.234));
+    assert_eq!(money!(0.000001 USDT).get_amount(), dec!(0.000001));
+    assert_eq!(
+        money!(3, FinMoneyCurrency::BTC).get_currency(),
+        FinMoneyCurrency::BTC
+    );
+    assert_eq!(money!(7 finmoney::iso4217::EUR).get_currency_code(), "EUR");
+}
+
+#[test]
+fn test_money_macro_preserves_scale() {
+    assert_eq!(money!(10.50 USD).to_string(), "10.50 USD");
+    assert_eq!(money!(10 USD).to_string(), "10 USD");
+}
@@ -0,0 +1,174 @@
+//! Tests for currency classification and metadata.
+
+use finmoney::{
+    FinCurrencyKind, FinCurrencyMetadata, FinMoney, FinMoneyCurrency, FinMoneyCurrencyRegistry,
+    FinMoneyError, iso4217,
+};
+use rust_decimal_macros::dec;
+
+#[test]
+fn test_set_and_get_metadata() -> Result<(), FinMoneyError> {
+    let usdt = FinMoneyCurrency::new(10, "USDT", Some("Tether".to_string()), 6)?;
+    let registry =
+        FinMoneyCurrencyRegistry::with_currencies([iso4217::EUR, FinMoneyCurrency::BTC, usdt])?;
+
+    registry.set_metadata(
+        iso4217::EUR,
+        FinCurrencyMetadata::new(FinCurrencyKind::Fiat)
+            .with_symbol("€")
+            .with_iso_numeric(978)
+            .with_minor_unit_name("cent"),
+    )?;
+    registry.set_metadata(
+        FinMoneyCurrency::BTC,
+        FinCurrencyMetadata::new(FinCurrencyKind::Crypto).with_minor_unit_name("satoshi"),
+    )?;
+    registry.set_metadata(usdt, FinCurrencyMetadata::new(FinCurrencyKind::Stablecoin))?;
+
+    let eur = registry.get_metadata(&iso4217::EUR).unwrap();
+    assert_eq!(eur.get_kind(), FinCurrencyKind::Fiat);
+    assert_eq!(eur.get_symbol(), Some("€"));
+    assert_eq!(eur.get_iso_numeric(), Some(978));
+    assert_eq!(eur.get_minor_unit_name(), Some("cent"));
+
+    assert_eq!(
+        registry.get_kind(&FinMoneyCurrency::BTC),
+        Some(FinCurrencyKind::Crypto)
+    );
+    assert_eq!(registry.get_kind(&usdt), Some(FinCurrencyKind::Stablecoin));
+    assert_eq!(registry.get_metadata(&usdt).unwrap().get_symbol(), None);
+    Ok(())
+}
+
+#[test]
+fn test_currencies_of_kind() -> Result<(), FinMoneyError> {
+    let miles = FinMoneyCurrency::new(20, "MILES", None, 0)?;
+    let registry = FinMoneyCurrencyRegistry::with_currencies([
+        FinMoneyCurrency::ETH,
+        FinMoneyCurrency::BTC,
+        iso4217::USD,
+        miles,
+    ])?;
+    for crypto in [FinMoneyCurrency::ETH, FinMoneyCurrency::BTC] {
+        registry.set_metadata(crypto, FinCurrencyMetadata::new(FinCurrencyKind::Crypto))?;
+    }
+    registry.set_metadata(miles, FinCurrencyMetadata::new(FinCurrencyKind::Points))?;
+
+    assert_eq!(
+        registry.currencies_of_kind(FinCurrencyKind::Crypto),
+        vec![FinMoneyCurrency::BTC, FinMoneyCurrency::ETH]
+    );
+    assert_eq!(
+        registry.currencies_of_kind(FinCurrencyKind::Points),
+        vec![miles]
+    );
+    assert!(
+        registry
+            .currencies_of_kind(FinCurrencyKind::Fiat)
+            .is_empty()
+    );
+    assert_eq!(registry.get_kind(&iso4217::USD), None);
+    Ok(())
+}
+
+#[test]
+fn test_iso4217_metadata() {
+    let usd = FinCurrencyMetadata::iso4217(&iso4217::USD).unwrap();
+    assert_eq!(usd.get_kind(), FinCurrencyKind::Fiat);
+    assert_eq!(usd.get_iso_numeric(), Some(840));
+
+    let clf = FinCurrencyMetadata::iso4217(&iso4217::CLF).unwrap();
+    assert_eq!(clf.get_kind(), FinCurrencyKind::Synthetic);
+
+    // `FinMoneyCurrency::USD` is the ISO entry; the crypto constants are not ISO currencies.
+    assert_eq!(
+        FinCurrencyMetadata::iso4217(&FinMoneyCurrency::USD),
+        Some(usd)
+    );
+    assert_eq!(FinCurrencyMetadata::iso4217(&FinMoneyCurrency::BTC), None);
+}
+
+#[test]
+fn test_metadata_requires_registration() {
+    let registry = FinMoneyCurrencyRegistry::new();
+    assert_eq!(
+        registry.set_metadata(
+            iso4217::USD,
+            FinCurrencyMetadata::new(FinCurrencyKind::Fiat)
+        ),
+        Err(FinMoneyError::UnknownCurrency("USD".to_string()))
+    );
+}
+
+#[test]
+fn test_kind_display() {
+    assert_eq!(FinCurrencyKind::Stablecoin.to_string(), "stablecoin");
+    assert_eq!(FinCurrencyKind::Points.to_string(), "points");
+}
+
+#[test]
+fn test_unicode_display_names_and_symbols() -> Result<(), FinMoneyError> {
+    // `new` sanitizes Unicode into the ASCII name; the strict constructor rejects it.
+    assert_eq!(
+        FinMoneyCurrency::try_new_strict(985, "PLN", Some("Złoty".to_string()), 2),
+        Err(FinMoneyError::InvalidCurrencyName("Złoty".to_string()))
+    );
+    assert_eq!(
+        FinMoneyCurrency::try_new_strict(985, "ZŁ", None, 2),
+        Err(FinMoneyError::InvalidCurrencyCode("ZŁ".to_string()))
+    );
+    assert_eq!(FinMoneyCurrency::new(985, "ZŁ", None, 2)?.get_code(), "Z_");
+
+    let pln = FinMoneyCurrency::new(985, "PLN", Some("Złoty".to_string()), 2)?;
+    assert_eq!(pln.get_name(), Some("Z_oty"));
+    let jpy = iso4217::JPY;
+    let registry = FinMoneyCurrencyRegistry::with_currencies([pln, jpy])?;
+
+    // Without metadata the sanitized ASCII name is all there is.
+    assert_eq!(registry.get_display_name(&pln).as_deref(), Some("Z_oty"));
+    assert_eq!(registry.get_symbol(&pln), None);
+
+    registry.set_metadata(
+        pln,
+        FinCurrencyMetadata::new(FinCurrencyKind::Fiat)
+            .with_display_name("Złoty")
+            .with_symbol("zł"),
+    )?;
+    registry.set_metadata(
+        jpy,
+        FinCurrencyMetadata::new(FinCurrencyKind::Fiat).with_symbol("円"),
+    )?;
+
+    assert_eq!(registry.get_display_name(&pln).as_deref(), Some("Złoty"));
+    assert_eq!(registry.get_symbol(&pln).as_deref(), Some("zł"));
+    assert_eq!(registry.get_display_name(&jpy).as_deref(), Some("Yen"));
+    assert_eq!(registry.get_symbol(&jpy).as_deref(), Some("円"));
+
+    // Another registry is unaffected.
+    let other = FinMoneyCurrencyRegistry::with_currencies([pln])?;
+    assert_eq!(other.get_display_name(&pln).as_deref(), Some("Z_oty"));
+
+    // A currency that only shares the id keeps its own name and gets no symbol.
+    let impostor = FinMoneyCurrency::new(985, "XPL", Some("Impostor".to_string()), 2)?;
+    assert_eq!(
+        registry.get_display_name(&impostor).as_deref(),
+        Some("Impostor")
+    );
+    assert_eq!(registry.get_symbol(&impostor), None);
+    Ok(())
+}
+
+#[test]
+fn test_money_to_symbol_string() -> Result<(), FinMoneyError> {
+    let registry = FinMoneyCurrencyRegistry::with_currencies([iso4217::EUR])?;
+    let amount = FinMoney::new(dec!(12.34), iso4217::EUR);
+    assert_eq!(amount.to_symbol_string(&registry), "12.34 EUR");
+
+    registry.set_metadata(
+        iso4217::EUR,
+        FinCurrencyMetadata::new(FinCurrencyKind::Fiat).with_symbol("€"),
+    )?;
+    assert_eq!(amount.to_symbol_string(&registry), "12.34 €");
+    assert_eq!(amount.to_string(), "12.34 EUR");
+    Ok(())
+}
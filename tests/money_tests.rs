@@ -1,6 +1,6 @@
 //! Comprehensive tests for the FinMoney type.
 
-use finmoney::{FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
+use finmoney::{Decimal, FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
 use rust_decimal_macros::dec;
 
 #[test]
@@ -37,7 +37,7 @@ fn test_fin_money_arithmetic() -> Result<(), FinMoneyError> {
     assert_eq!(diff.get_amount(), dec!(5.25));
 
     // Multiplication with decimal
-    let product = fin_money1 * dec!(2);
+    let product = (fin_money1 * dec!(2))?;
     assert_eq!(product.get_amount(), dec!(21.00));
 
     // Division
@@ -204,3 +204,125 @@ fn test_rescale() -> Result<(), FinMoneyError> {
 
     Ok(())
 }
+
+#[test]
+fn test_min_max_value() {
+    let usd = FinMoneyCurrency::USD;
+    assert_eq!(
+        FinMoney::max_value(usd).get_amount(),
+        dec!(792281625142643375935439503.35)
+    );
+    assert_eq!(
+        FinMoney::min_value(usd).get_amount(),
+        dec!(-792281625142643375935439503.35)
+    );
+    assert_eq!(
+        FinMoney::max_value(FinMoneyCurrency::USD.with_precision(0).unwrap()).get_amount(),
+        Decimal::MAX
+    );
+    assert_eq!(FinMoney::max_value(usd).get_currency(), usd);
+}
+
+#[test]
+fn test_saturating_arithmetic() -> Result<(), FinMoneyError> {
+    let usd = FinMoneyCurrency::USD;
+    let max = FinMoney::max_value(usd);
+    let min = FinMoney::min_value(usd);
+    let one = FinMoney::new(dec!(1), usd);
+
+    // In range: identical to checked arithmetic
+    let sum = FinMoney::new(dec!(10.50), usd).saturating_add(one)?;
+    assert_eq!(sum.get_amount(), dec!(11.50));
+    let diff = FinMoney::new(dec!(10.50), usd).saturating_sub(one)?;
+    assert_eq!(diff.get_amount(), dec!(9.50));
+
+    // Beyond the currency range but within Decimal range
+    assert_eq!(max.saturating_add(one)?, max);
+    assert_eq!(min.saturating_sub(one)?, min);
+
+    // Beyond Decimal range: an amount already past the bound is never pulled back
+    let huge = FinMoney::new(Decimal::MAX, usd);
+    assert_eq!(huge.saturating_add(huge)?, huge);
+    assert_eq!(huge.saturating_add(one)?, huge);
+    assert_eq!(huge.negated().saturating_sub(huge)?, huge.negated());
+    assert_eq!(huge.negated().saturating_sub(one)?, huge.negated());
+    assert_eq!(huge.saturating_mul_decimal(dec!(2)), huge);
+    assert_eq!(huge.saturating_mul_decimal(dec!(1.0000001)), huge);
+    assert_eq!(max.saturating_mul_decimal(dec!(1e10)), max);
+    assert_eq!(huge.saturating_mul_decimal(dec!(-2)), min);
+    assert_eq!(huge.negated().saturating_mul_decimal(dec!(-2)), max);
+
+    // An out-of-range amount is left alone by operations that do not overflow
+    let zero = FinMoney::zero(usd);
+    assert_eq!(huge.saturating_add(zero)?, huge);
+    assert_eq!(huge.negated().saturating_sub(zero)?, huge.negated());
+    assert_eq!(huge.saturating_mul_decimal(dec!(1)), huge);
+    assert_eq!(
+        huge.saturating_sub(one)?.get_amount(),
+        Decimal::MAX - dec!(1)
+    );
+    assert_eq!(
+        one.saturating_mul_decimal(dec!(2.5)).get_amount(),
+        dec!(2.5)
+    );
+
+    // Currency is still checked
+    let eur = FinMoney::new(dec!(1), FinMoneyCurrency::EUR);
+    assert!(matches!(
+        one.saturating_add(eur),
+        Err(FinMoneyError::CurrencyMismatch { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_try_assign_operations() -> Result<(), FinMoneyError> {
+    let usd = FinMoneyCurrency::USD;
+    let mut position = FinMoney::new(dec!(10.00), usd);
+
+    position.try_add_assign(FinMoney::new(dec!(2.50), usd))?;
+    assert_eq!(position.get_amount(), dec!(12.50));
+
+    position.try_sub_assign(FinMoney::new(dec!(0.50), usd))?;
+    assert_eq!(position.get_amount(), dec!(12.00));
+
+    position.try_mul_assign(dec!(1.5))?;
+    assert_eq!(position.get_amount(), dec!(18.000));
+
+    // Errors leave the value untouched
+    let eur = FinMoney::new(dec!(1), FinMoneyCurrency::EUR);
+    assert!(matches!(
+        position.try_add_assign(eur),
+        Err(FinMoneyError::CurrencyMismatch { .. })
+    ));
+    assert!(matches!(
+        position.try_sub_assign(eur),
+        Err(FinMoneyError::CurrencyMismatch { .. })
+    ));
+    assert_eq!(
+        position.try_mul_assign(Decimal::MAX),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    assert_eq!(position.get_amount(), dec!(18.000));
+
+    Ok(())
+}
+
+#[cfg(not(feature = "no-panic-ops"))]
+#[test]
+fn test_assign_operators() {
+    let usd = FinMoneyCurrency::USD;
+    let mut total = FinMoney::zero(usd);
+    total += FinMoney::new(dec!(5), usd);
+    total -= FinMoney::new(dec!(2), usd);
+    assert_eq!(total.get_amount(), dec!(3));
+}
+
+#[cfg(not(feature = "no-panic-ops"))]
+#[test]
+#[should_panic(expected = "AddAssign failed: Currency mismatch")]
+fn test_add_assign_panics_on_mismatch() {
+    let mut total = FinMoney::zero(FinMoneyCurrency::USD);
+    total += FinMoney::new(dec!(1), FinMoneyCurrency::EUR);
+}
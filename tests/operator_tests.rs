@@ -0,0 +1,109 @@
+//! Tests for the operator overloads of FinMoney.
+
+// Reference operands are exercised on purpose.
+#![allow(clippy::op_ref)]
+
+use finmoney::{Decimal, FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy};
+use rust_decimal_macros::dec;
+
+#[test]
+fn test_neg() {
+    let usd = FinMoneyCurrency::USD;
+    let amount = FinMoney::new(dec!(10.50), usd);
+
+    assert_eq!((-amount).get_amount(), dec!(-10.50));
+    assert_eq!((-&amount).get_amount(), dec!(-10.50));
+    assert_eq!(-(-amount), amount);
+}
+
+#[test]
+fn test_div_uses_default_rounding() -> Result<(), FinMoneyError> {
+    let usd = FinMoneyCurrency::USD;
+    let amount = FinMoney::new(dec!(10), usd);
+
+    let third = (amount / dec!(3))?;
+    assert_eq!(
+        third,
+        amount.divided_by_decimal(dec!(3), FinMoneyRoundingStrategy::default())?
+    );
+    assert_eq!(third.get_amount(), dec!(3.33));
+
+    // Midpoint rounds to even
+    assert_eq!(
+        (FinMoney::new(dec!(0.25), usd) / dec!(2))?.get_amount(),
+        dec!(0.12)
+    );
+
+    assert_eq!(amount / dec!(0), Err(FinMoneyError::DivisionByZero));
+    Ok(())
+}
+
+#[test]
+fn test_rem() -> Result<(), FinMoneyError> {
+    let usd = FinMoneyCurrency::USD;
+
+    let remainder = (FinMoney::new(dec!(10.60), usd) % dec!(0.25))?;
+    assert_eq!(remainder.get_amount(), dec!(0.10));
+    assert_eq!(remainder.get_currency(), usd);
+
+    let negative = (FinMoney::new(dec!(-10.60), usd) % dec!(0.25))?;
+    assert_eq!(negative.get_amount(), dec!(-0.10));
+
+    assert_eq!(
+        FinMoney::new(dec!(1), usd) % Decimal::ZERO,
+        Err(FinMoneyError::DivisionByZero)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_decimal_on_the_left() -> Result<(), FinMoneyError> {
+    let amount = FinMoney::new(dec!(10.50), FinMoneyCurrency::USD);
+
+    assert_eq!((dec!(2) * amount)?, (amount * dec!(2))?);
+    assert_eq!((dec!(2) * &amount)?.get_amount(), dec!(21.00));
+    assert_eq!(
+        Decimal::MAX * FinMoney::new(dec!(2), FinMoneyCurrency::USD),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_reference_operands() -> Result<(), FinMoneyError> {
+    let usd = FinMoneyCurrency::USD;
+    let a = FinMoney::new(dec!(10.50), usd);
+    let b = FinMoney::new(dec!(5.25), usd);
+
+    assert_eq!((&a + &b)?.get_amount(), dec!(15.75));
+    assert_eq!((a + &b)?.get_amount(), dec!(15.75));
+    assert_eq!((&a + b)?.get_amount(), dec!(15.75));
+    assert_eq!((&a - &b)?.get_amount(), dec!(5.25));
+    assert_eq!((&a * dec!(2))?.get_amount(), dec!(21.00));
+    assert_eq!((&a * &dec!(2))?.get_amount(), dec!(21.00));
+    assert_eq!((&a / dec!(2))?.get_amount(), dec!(5.25));
+    assert_eq!((&a % &dec!(4))?.get_amount(), dec!(2.50));
+
+    let eur = FinMoney::new(dec!(1), FinMoneyCurrency::EUR);
+    assert!(matches!(
+        &a + &eur,
+        Err(FinMoneyError::CurrencyMismatch { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_operators_in_generic_code() -> Result<(), FinMoneyError> {
+    fn add_refs<T>(a: &T, b: &T) -> Result<T, FinMoneyError>
+    where
+        for<'x> &'x T: std::ops::Add<&'x T, Output = Result<T, FinMoneyError>>,
+    {
+        a + b
+    }
+
+    let usd = FinMoneyCurrency::USD;
+    let a = FinMoney::new(dec!(1), usd);
+    let b = FinMoney::new(dec!(2), usd);
+    assert_eq!(add_refs(&a, &b)?.get_amount(), dec!(3));
+    Ok(())
+}
@@ -0,0 +1,250 @@
+//! Tests for currency pairs and quoting conventions.
+
+use finmoney::{
+    FinCurrencyPair, FinMoneyCurrency, FinMoneyCurrencyRegistry, FinMoneyError,
+    FinMoneyRoundingStrategy, FinPairConvention, FinPairConventions, iso4217,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn crypto_registry() -> Result<(FinMoneyCurrencyRegistry, FinMoneyCurrency), FinMoneyError> {
+    let registry = FinMoneyCurrencyRegistry::new();
+    let usdt = FinMoneyCurrency::new(10, "USDT", None, 6)?;
+    registry.register_all([FinMoneyCurrency::BTC, FinMoneyCurrency::USD, usdt])?;
+    Ok((registry, usdt))
+}
+
+#[test]
+fn test_parse_iso_formats() -> Result<(), FinMoneyError> {
+    for input in ["EUR/USD", "EURUSD", "eur-usd", "EUR_USD", " EURUSD "] {
+        let pair: FinCurrencyPair = input.parse()?;
+        assert_eq!(pair.get_base(), iso4217::EUR, "{input}");
+        assert_eq!(pair.get_quote(), iso4217::USD, "{input}");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parse_against_registry() -> Result<(), FinMoneyError> {
+    let (registry, usdt) = crypto_registry()?;
+
+    let dashed = FinCurrencyPair::parse("BTC-USDT", &registry)?;
+    let joined = FinCurrencyPair::parse("BTCUSDT", &registry)?;
+    assert_eq!(dashed, joined);
+    assert_eq!(joined.get_quote(), usdt);
+
+    let usd = FinCurrencyPair::parse("BTCUSD", &registry)?;
+    assert_eq!(usd.get_quote(), FinMoneyCurrency::USD);
+    Ok(())
+}
+
+#[test]
+fn test_parse_ignores_case() -> Result<(), FinMoneyError> {
+    let registry = FinMoneyCurrencyRegistry::new();
+    let usdt = FinMoneyCurrency::new(10, "usdt", None, 6)?;
+    registry.register_all([FinMoneyCurrency::BTC, usdt])?;
+
+    for input in ["btc/usdt", "BTC/USDT", "BTCusdt", "btcUSDT"] {
+        let pair = FinCurrencyPair::parse(input, &registry)?;
+        assert_eq!(pair.get_base(), FinMoneyCurrency::BTC, "{input}");
+        assert_eq!(pair.get_quote(), usdt, "{input}");
+    }
+
+    // With two codes differing only in case, only exact matches resolve.
+    let upper = FinMoneyCurrency::new(11, "USDT", None, 6)?;
+    registry.register(upper)?;
+    assert_eq!(
+        FinCurrencyPair::parse("btc/usdt", &registry)?.get_quote(),
+        usdt
+    );
+    assert_eq!(
+        FinCurrencyPair::parse("btc/USDT", &registry)?.get_quote(),
+        upper
+    );
+    assert_eq!(
+        FinCurrencyPair::parse("btc/Usdt", &registry),
+        Err(FinMoneyError::UnknownCurrency("Usdt".to_string()))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_errors() -> Result<(), FinMoneyError> {
+    let (registry, _) = crypto_registry()?;
+
+    assert_eq!(
+        FinCurrencyPair::parse("BTC/XYZ", &registry),
+        Err(FinMoneyError::UnknownCurrency("XYZ".to_string()))
+    );
+    assert_eq!(
+        "EUR/XYZ".parse::<FinCurrencyPair>(),
+        Err(FinMoneyError::UnknownCurrency("XYZ".to_string()))
+    );
+
+    // Malformed codes are syntax errors, whether or not a registry is consulted.
+    for (input, code) in [
+        ("BTC/", ""),
+        ("BT C/USDT", "BT C"),
+        ("BTC/ÜSD", "ÜSD"),
+        ("BTC/ABCDEFGHIJKLMNOPQ", "ABCDEFGHIJKLMNOPQ"),
+    ] {
+        assert_eq!(
+            FinCurrencyPair::parse(input, &registry),
+            Err(FinMoneyError::InvalidCurrencyCode(code.to_string())),
+            "{input}"
+        );
+    }
+    assert!(matches!(
+        FinCurrencyPair::parse("BTCXYZ", &registry),
+        Err(FinMoneyError::InvalidCurrencyPair(_))
+    ));
+    assert!(matches!(
+        "USD/USD".parse::<FinCurrencyPair>(),
+        Err(FinMoneyError::InvalidCurrencyPair(_))
+    ));
+
+    // Both "AB"+"CDE" and "ABC"+"DE" resolve.
+    let ambiguous = FinMoneyCurrencyRegistry::new();
+    ambiguous.register_all([
+        FinMoneyCurrency::new(1, "AB", None, 2)?,
+        FinMoneyCurrency::new(2, "CDE", None, 2)?,
+        FinMoneyCurrency::new(3, "ABC", None, 2)?,
+        FinMoneyCurrency::new(4, "DE", None, 2)?,
+    ])?;
+    assert!(matches!(
+        FinCurrencyPair::parse("ABCDE", &ambiguous),
+        Err(FinMoneyError::InvalidCurrencyPair(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_inverse_and_display() -> Result<(), FinMoneyError> {
+    let pair: FinCurrencyPair = "EURUSD".parse()?;
+    let inverse = pair.inverse();
+
+    assert_eq!(inverse.to_string(), "USD/EUR");
+    assert_eq!(inverse.inverse(), pair);
+    assert!(pair.is_same_pair(&inverse));
+    assert!(!pair.is_same_pair(&"EUR/GBP".parse()?));
+    Ok(())
+}
+
+#[test]
+fn test_market_ordering() -> Result<(), FinMoneyError> {
+    let conventions = FinPairConventions::market();
+
+    for (given, market) in [
+        ("USD/EUR", "EUR/USD"),
+        ("JPY/GBP", "GBP/JPY"),
+        ("CHF/USD", "USD/CHF"),
+        ("SEK/EUR", "EUR/SEK"),
+        ("SEK/NOK", "SEK/NOK"),
+    ] {
+        let pair: FinCurrencyPair = given.parse()?;
+        assert_eq!(conventions.normalize(pair).to_string(), market);
+    }
+    assert!(conventions.is_market_order("EUR/USD".parse()?));
+    assert!(!conventions.is_market_order("USD/EUR".parse()?));
+    Ok(())
+}
+
+#[test]
+fn test_conventions() -> Result<(), FinMoneyError> {
+    let conventions = FinPairConventions::market();
+
+    let eur_usd = conventions.get_convention("EUR/USD".parse()?);
+    assert_eq!(eur_usd.get_pip_size(), dec!(0.0001));
+    assert_eq!(
+        eur_usd.round_rate(
+            dec!(1.0850123),
+            FinMoneyRoundingStrategy::MidpointNearestEven
+        ),
+        dec!(1.08501)
+    );
+    assert_eq!(eur_usd.to_pips(dec!(0.0025))?, dec!(25));
+
+    let jpy_usd = conventions.get_convention("JPY/USD".parse()?);
+    assert_eq!(jpy_usd.get_pip_size(), dec!(0.01));
+    assert_eq!(jpy_usd.get_quote_precision(), 3);
+    assert_eq!(jpy_usd.from_pips(dec!(15))?, dec!(0.15));
+
+    let (registry, usdt) = crypto_registry()?;
+    let custom = FinPairConvention::new(2, dec!(0.01))?;
+    let crypto = FinPairConventions::new()
+        .with_priority(&[FinMoneyCurrency::BTC, usdt])
+        .with_convention(FinCurrencyPair::parse("BTCUSDT", &registry)?, custom);
+    let pair = FinCurrencyPair::parse("USDT-BTC", &registry)?;
+    assert_eq!(crypto.normalize(pair).to_string(), "BTC/USDT");
+    assert_eq!(crypto.get_convention(pair), custom);
+    assert_eq!(
+        crypto.get_convention("EUR/USD".parse()?),
+        FinPairConvention::default()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_convention() {
+    assert_eq!(
+        FinPairConvention::new(5, dec!(0)),
+        Err(FinMoneyError::InvalidTick)
+    );
+    assert_eq!(
+        FinPairConvention::new(5, dec!(-0.0001)),
+        Err(FinMoneyError::InvalidTick)
+    );
+    assert_eq!(
+        FinPairConvention::new(29, dec!(0.0001)),
+        Err(FinMoneyError::InvalidPrecision(29))
+    );
+}
+
+#[test]
+fn test_pip_conversion_overflow() {
+    let convention = FinPairConvention::default();
+    assert_eq!(
+        convention.to_pips(dec!(1e25)),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        convention.to_pips(Decimal::MAX),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    let coarse = FinPairConvention::new(0, dec!(100)).unwrap();
+    assert_eq!(
+        coarse.from_pips(Decimal::MAX),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_deserialize_validates() -> Result<(), Box<dyn std::error::Error>> {
+    let convention = FinPairConvention::new(3, dec!(0.01))?;
+    let json = serde_json::to_value(convention)?;
+    assert_eq!(
+        serde_json::from_value::<FinPairConvention>(json.clone())?,
+        convention
+    );
+
+    let mut zero_pip = json.clone();
+    zero_pip["pip_size"] = "0".into();
+    assert!(serde_json::from_value::<FinPairConvention>(zero_pip).is_err());
+
+    let mut too_precise = json;
+    too_precise["quote_precision"] = 29.into();
+    assert!(serde_json::from_value::<FinPairConvention>(too_precise).is_err());
+
+    let pair: FinCurrencyPair = "EUR/USD".parse()?;
+    let json = serde_json::to_value(pair)?;
+    assert_eq!(
+        serde_json::from_value::<FinCurrencyPair>(json.clone())?,
+        pair
+    );
+
+    let mut same = json;
+    same["quote"] = same["base"].clone();
+    assert!(serde_json::from_value::<FinCurrencyPair>(same).is_err());
+    Ok(())
+}
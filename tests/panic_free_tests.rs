@@ -0,0 +1,494 @@
+//! Property tests asserting that the public API never panics.
+//!
+//! Every operation is exercised with arbitrary amounts (including `Decimal::MAX`/`MIN` and
+//! values at the maximum scale). A panic inside a property fails the test.
+//!
+//! The documented exceptions are not exercised: `FinMoney`'s `+=`/`-=` (removed by the
+//! `no-panic-ops` feature), the deprecated `sqrt()`, and the `+`, `-`, `+=`, `-=` and
+//! `* Decimal` operators of `TypedMoney`, which panic on overflow and are only exercised where
+//! the result is in range.
+
+use finmoney::typed::{BTC, TypedMoney, USD};
+use finmoney::{
+    Decimal, FinAsOf, FinCurrencyPair, FinDate, FinDayCount, FinDenomination, FinExchangeRate,
+    FinFixedRateTable, FinInterestRate, FinMoney, FinMoneyBag, FinMoneyCurrency, FinMoneyError,
+    FinMoneyIteratorExt, FinMoneyRemainderStrategy, FinMoneyRoundingStrategy, FinMoneyWaterfall,
+    FinMoneyWaterfallTier, FinPairConvention, FinPairConventions, FinQuote, FinRateHistory,
+    FinTriangulation, iso4217,
+};
+use proptest::prelude::*;
+
+fn any_decimal() -> impl Strategy<Value = Decimal> {
+    prop_oneof![
+        (
+            any::<u32>(),
+            any::<u32>(),
+            any::<u32>(),
+            any::<bool>(),
+            0u32..=28
+        )
+            .prop_map(|(lo, mid, hi, neg, scale)| Decimal::from_parts(lo, mid, hi, neg, scale)),
+        (any::<i64>(), 0u32..=28).prop_map(|(n, scale)| Decimal::new(n, scale)),
+        Just(Decimal::MAX),
+        Just(Decimal::MIN),
+        Just(Decimal::ZERO),
+        Just(Decimal::new(1, 28)),
+    ]
+}
+
+fn any_currency() -> impl Strategy<Value = FinMoneyCurrency> {
+    prop_oneof![
+        Just(FinMoneyCurrency::USD),
+        Just(FinMoneyCurrency::EUR),
+        (0u8..=28).prop_map(|p| FinMoneyCurrency::BTC.with_precision(p).unwrap()),
+    ]
+}
+
+fn any_money() -> impl Strategy<Value = FinMoney> {
+    (any_decimal(), any_currency()).prop_map(|(amount, currency)| FinMoney::new(amount, currency))
+}
+
+fn any_strategy() -> impl Strategy<Value = FinMoneyRoundingStrategy> {
+    prop_oneof![
+        Just(FinMoneyRoundingStrategy::MidpointNearestEven),
+        Just(FinMoneyRoundingStrategy::MidpointAwayFromZero),
+        Just(FinMoneyRoundingStrategy::MidpointTowardZero),
+        Just(FinMoneyRoundingStrategy::ToZero),
+        Just(FinMoneyRoundingStrategy::AwayFromZero),
+        Just(FinMoneyRoundingStrategy::ToNegativeInfinity),
+        Just(FinMoneyRoundingStrategy::ToPositiveInfinity),
+    ]
+}
+
+fn any_remainder_strategy() -> impl Strategy<Value = FinMoneyRemainderStrategy> {
+    prop_oneof![
+        Just(FinMoneyRemainderStrategy::First),
+        Just(FinMoneyRemainderStrategy::Last),
+        Just(FinMoneyRemainderStrategy::LargestFraction),
+        Just(FinMoneyRemainderStrategy::RoundRobin),
+    ]
+}
+
+fn any_date() -> impl Strategy<Value = FinDate> {
+    (1u16..=9999, 1u8..=12, 1u8..=28).prop_map(|(y, m, d)| FinDate::new(y, m, d).unwrap())
+}
+
+fn is_expected_error(result: &Result<FinMoney, FinMoneyError>) -> bool {
+    matches!(
+        result,
+        Ok(_)
+            | Err(FinMoneyError::ArithmeticOverflow)
+            | Err(FinMoneyError::CurrencyMismatch { .. })
+            | Err(FinMoneyError::DivisionByZero)
+    )
+}
+
+proptest! {
+    #[test]
+    fn binary_money_operations_never_panic(
+        a in any_money(),
+        b in any_money(),
+        strategy in any_strategy(),
+    ) {
+        prop_assert!(is_expected_error(&a.checked_add(b)));
+        prop_assert!(is_expected_error(&a.checked_sub(b)));
+        prop_assert!(is_expected_error(&(a + b)));
+        prop_assert!(is_expected_error(&(a - b)));
+        prop_assert!(is_expected_error(&a.plus_money(b)));
+        prop_assert!(is_expected_error(&a.minus_money(b)));
+        prop_assert!(is_expected_error(&a.multiplied_by_money(b)));
+        prop_assert!(is_expected_error(&a.divided_by_money(b, strategy)));
+
+        let _ = a.percent_change_from(b);
+        let _ = a.negative_percent_change_from(b);
+        let _ = FinMoney::percent_change(a, b);
+        let _ = FinMoney::negative_percent_change(a, b);
+        let _ = a.compare(b);
+        let _ = a.min(b);
+        let _ = a.max(b);
+        let _ = a.is_less_than(b);
+        let _ = a.is_greater_than_or_equal(b);
+        let _ = a.is_equal_to(b);
+    }
+
+    #[test]
+    fn decimal_operations_never_panic(
+        a in any_money(),
+        d in any_decimal(),
+        strategy in any_strategy(),
+        dp in 0u32..=40,
+        precision in 0u8..=40,
+    ) {
+        prop_assert!(is_expected_error(&a.checked_add_decimal(d)));
+        prop_assert!(is_expected_error(&a.checked_sub_decimal(d)));
+        prop_assert!(is_expected_error(&a.checked_mul_decimal(d)));
+        prop_assert!(is_expected_error(&(a * d)));
+        prop_assert!(is_expected_error(&a.checked_div(d, strategy)));
+        prop_assert!(is_expected_error(&a.divided_by_decimal(d, strategy)));
+
+        let _ = a.to_tick(d, strategy);
+        let _ = a.to_tick_down(d);
+        let _ = a.to_tick_up(d);
+        let _ = a.to_tick_nearest(d);
+        let _ = a.is_multiple_of_tick(d);
+        let _ = FinMoney::tick_power10_dp(d);
+        let _ = a.round_dp(dp);
+        let _ = a.round_dp_with_strategy(dp, strategy);
+        let _ = a.rescale(precision);
+        let _ = FinMoney::new_with_precision(d, a.get_currency(), strategy);
+        let _ = a.is_less_than_decimal(d);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_decimal_operations_saturate(a in any_money(), d in any_decimal()) {
+        let max = FinMoney::max_value(a.get_currency());
+        let min = FinMoney::min_value(a.get_currency());
+        for result in [a.plus_decimal(d), a.minus_decimal(d), a.multiplied_by_decimal(d)] {
+            prop_assert_eq!(result.get_currency(), a.get_currency());
+            if result.get_amount() > max.get_amount() || result.get_amount() < min.get_amount() {
+                // Only an amount that was already out of range may stay out of range.
+                prop_assert!(a.get_amount() > max.get_amount() || a.get_amount() < min.get_amount());
+            }
+        }
+    }
+
+    #[test]
+    fn saturating_operations_follow_the_operation(a in any_decimal(), b in any_decimal()) {
+        let usd = FinMoneyCurrency::USD;
+        let x = FinMoney::new(a, usd);
+        let y = FinMoney::new(b, usd);
+        let sum = x.saturating_add(y).unwrap().get_amount();
+        let diff = x.saturating_sub(y).unwrap().get_amount();
+        if b.is_sign_negative() {
+            prop_assert!(sum <= a && diff >= a);
+        } else {
+            prop_assert!(sum >= a && diff <= a);
+        }
+    }
+
+    #[test]
+    fn typed_money_never_panics(
+        a in any_decimal(),
+        b in any_decimal(),
+        strategy in any_strategy(),
+    ) {
+        let x = TypedMoney::<USD>::new(a);
+        let y = TypedMoney::<USD>::new(b);
+        let _ = -x;
+        let mut total = x;
+        let _ = total.try_add_assign(y);
+        let _ = total.try_sub_assign(y);
+        let _ = x.saturating_add(y);
+        let _ = x.saturating_sub(y);
+        let _ = x.saturating_mul_decimal(b);
+        let _ = x.checked_add(y);
+        let _ = x.checked_sub(y);
+        let _ = x.checked_mul_decimal(b);
+        let _ = x.divided_by_decimal(b, strategy);
+        let _ = TypedMoney::<BTC>::new_with_precision(a, strategy);
+        let _ = TypedMoney::<USD>::try_from(FinMoney::new(b, FinMoneyCurrency::EUR));
+        let _ = x.to_string();
+    }
+
+    #[test]
+    fn typed_operators_match_checked_arithmetic(a in any_decimal(), b in any_decimal()) {
+        let x = TypedMoney::<USD>::new(a);
+        let y = TypedMoney::<USD>::new(b);
+        match a.checked_add(b) {
+            Some(sum) => prop_assert_eq!(x + y, TypedMoney::new(sum)),
+            None => prop_assert_eq!(x.checked_add(y), Err(FinMoneyError::ArithmeticOverflow)),
+        }
+        if let Some(difference) = a.checked_sub(b) {
+            prop_assert_eq!(x - y, TypedMoney::new(difference));
+        }
+        if let Some(product) = a.checked_mul(b) {
+            prop_assert_eq!(x * b, TypedMoney::new(product));
+        }
+    }
+
+    #[test]
+    fn unary_operations_never_panic(a in any_money(), strategy in any_strategy()) {
+        let _ = a.checked_sqrt();
+        let _ = a.abs();
+        let _ = a.negated();
+        let _ = a.normalize();
+        let _ = a.floor();
+        let _ = a.ceil();
+        let _ = a.trunc();
+        let _ = a.rounded(strategy);
+        let _ = a.is_integer();
+        let _ = a.is_positive();
+        let _ = a.to_string();
+    }
+
+    #[test]
+    fn allocation_never_panics(
+        a in any_money(),
+        ratios in prop::collection::vec(any_decimal(), 0..6),
+        n in 0usize..8,
+        strategy in any_remainder_strategy(),
+    ) {
+        if let Ok(shares) = a.allocate_with(&ratios, strategy) {
+            let total = shares.iter().try_fold(Decimal::ZERO, |acc, s| acc.checked_add(s.get_amount()));
+            prop_assert_eq!(total, Some(a.get_amount()));
+        }
+        let _ = a.split_evenly_with(n, strategy);
+    }
+
+    #[test]
+    fn waterfall_never_panics(
+        a in any_money(),
+        cap in any_decimal(),
+        hurdle in any_decimal(),
+        weights in prop::collection::vec(any_decimal(), 0..4),
+    ) {
+        let currency = a.get_currency();
+        let mut tier = FinMoneyWaterfallTier::new("senior", 1)
+            .with_cap(FinMoney::new(cap, currency))
+            .with_hurdle(FinMoney::new(hurdle, currency));
+        for (i, weight) in weights.iter().enumerate() {
+            tier = tier.with_split(format!("party-{}", i), *weight);
+        }
+        let residual = FinMoneyWaterfallTier::new("residual", 2).with_split("equity", Decimal::ONE);
+        let waterfall = FinMoneyWaterfall::new().with_tier(tier).with_tier(residual);
+        let _ = waterfall.distribute(a);
+    }
+
+    #[test]
+    fn bag_and_iterators_never_panic(
+        amounts in prop::collection::vec(any_money(), 0..6),
+        rate in any_decimal(),
+        strategy in any_strategy(),
+    ) {
+        let mut bag = FinMoneyBag::new();
+        for money in &amounts {
+            let _ = bag.add(*money);
+            let _ = bag.subtract(*money);
+        }
+        let _ = bag.clone().merge(&bag);
+        let _ = bag.negated();
+        let _ = bag.collapse(FinMoneyCurrency::USD, |_, _| Some(rate), strategy);
+        let _ = amounts.iter().try_sum();
+        let _ = amounts.iter().try_sum_in(FinMoneyCurrency::USD);
+        let _ = amounts.iter().try_min();
+        let _ = amounts.iter().try_max();
+    }
+
+    #[test]
+    fn exchange_rates_never_panic(
+        a in any_money(),
+        rate in any_decimal(),
+        bid in any_decimal(),
+        ask in any_decimal(),
+        dp in 0u32..=30,
+        strategy in any_strategy(),
+    ) {
+        let base = a.get_currency();
+        if let Ok(fx) = FinExchangeRate::new(base, iso4217::JPY, rate) {
+            let _ = fx.convert(&a, strategy);
+            let _ = fx.inverse(dp, strategy);
+        }
+        if let Ok(quote) = FinQuote::new(base, iso4217::JPY, bid, ask) {
+            prop_assert!(quote.spread() >= Decimal::ZERO);
+            let _ = quote.mid();
+            let _ = quote.spread_bps();
+            let _ = quote.spread_ticks(rate);
+            let _ = quote.convert_buy(&a);
+            let _ = quote.convert_sell(&a);
+        }
+    }
+
+    #[test]
+    fn pair_conventions_never_panic(
+        precision in 0u32..=30,
+        pip_size in any_decimal(),
+        value in any_decimal(),
+        strategy in any_strategy(),
+    ) {
+        if let Ok(convention) = FinPairConvention::new(precision, pip_size) {
+            let _ = convention.round_rate(value, strategy);
+            let _ = convention.to_pips(value);
+            let _ = convention.from_pips(value);
+        }
+        let pair = FinCurrencyPair::new(iso4217::USD, iso4217::JPY).unwrap();
+        let _ = FinPairConventions::market().get_convention(pair.inverse());
+    }
+
+    #[test]
+    fn denominations_never_panic(amount in any_decimal(), factor in any_decimal()) {
+        let btc = FinMoney::new(amount, FinMoneyCurrency::BTC);
+        let _ = btc.to_unit("sat");
+        let _ = FinMoney::from_unit(amount, "sat", FinMoneyCurrency::BTC);
+        let _ = FinMoney::from_unit(amount, "gwei", FinMoneyCurrency::ETH);
+        let _ = FinDenomination::new("unit", factor);
+    }
+
+    #[test]
+    fn changeover_never_panics(a in any_decimal(), rate in any_decimal()) {
+        let dem = FinMoneyCurrency::new(276, "DEM", None, 2).unwrap();
+        let frf = FinMoneyCurrency::new(250, "FRF", None, 2).unwrap();
+        let mut table = FinFixedRateTable::new(iso4217::EUR);
+        let _ = table.insert(dem, rate);
+        let _ = table.insert(frf, Decimal::new(655_957, 5));
+        let _ = table.convert(&FinMoney::new(a, dem), frf);
+        let _ = table.convert(&FinMoney::new(a, iso4217::EUR), frf);
+    }
+
+    #[test]
+    fn forwards_and_history_never_panic(
+        spot in any_decimal(),
+        rate in any_decimal(),
+        points in any_decimal(),
+        start in any_date(),
+        end in any_date(),
+        at in any::<i64>(),
+        keys in prop::collection::vec((any::<i64>(), any_decimal()), 0..4),
+    ) {
+        let interest = FinInterestRate::new(rate, FinDayCount::Thirty360);
+        let _ = interest.growth_factor(start, end);
+        if let Ok(fx) = FinExchangeRate::new(iso4217::EUR, iso4217::USD, spot) {
+            let convention = FinPairConvention::new(5, Decimal::new(1, 4)).unwrap();
+            let _ = fx.forward_from_points(points, &convention);
+            let _ = fx.forward_from_interest(interest, interest, start, end);
+        }
+
+        let mut history = FinRateHistory::<i64>::new();
+        for (key, value) in keys {
+            if let Ok(fx) = FinExchangeRate::new(iso4217::EUR, iso4217::USD, value) {
+                history.insert(key, fx);
+            }
+        }
+        for as_of in [FinAsOf::Exact, FinAsOf::Previous, FinAsOf::Interpolated] {
+            let _ = history.get_rate(iso4217::USD, iso4217::EUR, at, as_of);
+            let _ = history.snapshot(at, as_of, FinTriangulation::Direct);
+        }
+    }
+
+    #[test]
+    fn checked_results_match_decimal_arithmetic(a in any_decimal(), b in any_decimal()) {
+        let usd = FinMoneyCurrency::USD;
+        let x = FinMoney::new(a, usd);
+        let y = FinMoney::new(b, usd);
+
+        match a.checked_add(b) {
+            Some(sum) => prop_assert_eq!(x.checked_add(y).map(|m| m.get_amount()), Ok(sum)),
+            None => prop_assert_eq!(x.checked_add(y), Err(FinMoneyError::ArithmeticOverflow)),
+        }
+        match a.checked_mul(b) {
+            Some(product) => {
+                prop_assert_eq!(x.checked_mul_decimal(b).map(|m| m.get_amount()), Ok(product))
+            }
+            None => prop_assert_eq!(
+                x.checked_mul_decimal(b),
+                Err(FinMoneyError::ArithmeticOverflow)
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+proptest! {
+    #[test]
+    fn deserialized_currencies_are_validated(
+        id in any::<i32>(),
+        code in "[A-Z]{1,16}",
+        name in proptest::option::of("[ -~]{0,52}"),
+        precision in any::<u8>(),
+    ) {
+        let json = serde_json::json!({
+            "id": id,
+            "name": name,
+            "code": code,
+            "precision": precision,
+        });
+        let result = serde_json::from_value::<FinMoneyCurrency>(json);
+        prop_assert_eq!(result.is_ok(), precision <= 28);
+        if let Ok(currency) = result {
+            prop_assert_eq!(currency.get_precision(), precision);
+            let json = serde_json::to_string(&currency).unwrap();
+            prop_assert_eq!(serde_json::from_str::<FinMoneyCurrency>(&json).unwrap(), currency);
+        }
+    }
+
+    #[test]
+    fn deserialized_currency_ranges_never_panic(
+        precision in any::<u8>(),
+        a in any_decimal(),
+        b in any_decimal(),
+    ) {
+        let json = format!(r#"{{"id":7,"name":null,"code":"HPC","precision":{precision}}}"#);
+        if let Ok(currency) = serde_json::from_str::<FinMoneyCurrency>(&json) {
+            let max = FinMoney::max_value(currency);
+            prop_assert_eq!(FinMoney::min_value(currency), -max);
+            let x = FinMoney::new(a, currency);
+            let y = FinMoney::new(b, currency);
+            let _ = x.saturating_add(y);
+            let _ = x.saturating_sub(y);
+            let _ = x.saturating_mul_decimal(b);
+            let _ = FinMoney::new_with_precision(a, currency, FinMoneyRoundingStrategy::ToZero);
+        }
+    }
+}
+
+#[test]
+fn test_overflow_is_reported() {
+    let usd = FinMoneyCurrency::USD;
+    let max = FinMoney::new(Decimal::MAX, usd);
+    let min = FinMoney::new(Decimal::MIN, usd);
+
+    assert_eq!(
+        max.checked_add(FinMoney::new(Decimal::ONE, usd)),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        min.checked_sub(FinMoney::new(Decimal::ONE, usd)),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    assert_eq!(max * Decimal::TWO, Err(FinMoneyError::ArithmeticOverflow));
+    assert_eq!(
+        max.checked_div(Decimal::new(1, 28), FinMoneyRoundingStrategy::ToZero),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        max.to_tick_nearest(Decimal::new(3, 28)),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        max.percent_change_from(FinMoney::new(Decimal::ONE, usd)),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    // Decimal::MAX is 2^96 - 1, which is divisible by 3 but not by 11.
+    assert!(max.is_multiple_of_tick(Decimal::new(3, 28)));
+    assert!(!max.is_multiple_of_tick(Decimal::new(11, 28)));
+}
+
+#[test]
+fn test_checked_sqrt() {
+    let usd = FinMoneyCurrency::USD;
+    let root = FinMoney::new(Decimal::from(9), usd).checked_sqrt().unwrap();
+    assert_eq!(root.get_amount(), Decimal::from(3));
+
+    let negative = FinMoney::new(Decimal::from(-9), usd).checked_sqrt();
+    assert!(matches!(negative, Err(FinMoneyError::InvalidAmount(_))));
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_decimal_operations_saturate() {
+    let usd = FinMoneyCurrency::USD;
+    let max = FinMoney::max_value(usd);
+    let min = FinMoney::min_value(usd);
+
+    assert_eq!(max.plus_decimal(Decimal::MAX), max);
+    assert_eq!(min.minus_decimal(Decimal::MAX), min);
+    assert_eq!(max.multiplied_by_decimal(Decimal::from(-2)), min);
+    // An amount already outside the currency range is never moved against the operation.
+    let huge = FinMoney::new(Decimal::MAX, usd);
+    assert_eq!(huge.plus_decimal(Decimal::ONE), huge);
+    assert_eq!(huge.minus_decimal(Decimal::from(-1)), huge);
+    assert_eq!(
+        FinMoney::new(Decimal::from(2), usd).plus_decimal(Decimal::ONE),
+        FinMoney::new(Decimal::from(3), usd)
+    );
+}
@@ -0,0 +1,162 @@
+//! Tests for two-sided quotes.
+
+use finmoney::{FinMoney, FinMoneyCurrency, FinMoneyError, FinQuote, iso4217};
+use rust_decimal_macros::dec;
+
+fn eur_usd() -> Result<FinQuote, FinMoneyError> {
+    FinQuote::new(
+        FinMoneyCurrency::EUR,
+        FinMoneyCurrency::USD,
+        dec!(1.0848),
+        dec!(1.0852),
+    )
+}
+
+#[test]
+fn test_mid_and_spread() -> Result<(), FinMoneyError> {
+    let quote = eur_usd()?;
+
+    assert_eq!(quote.mid(), dec!(1.0850));
+    assert_eq!(quote.spread(), dec!(0.0004));
+    assert_eq!(quote.spread_bps().round_dp(4), dec!(3.6866));
+    assert_eq!(quote.spread_ticks(dec!(0.0001))?, dec!(4));
+    assert_eq!(quote.spread_ticks(dec!(0.00005))?, dec!(8));
+    assert_eq!(quote.spread_ticks(dec!(0)), Err(FinMoneyError::InvalidTick));
+    Ok(())
+}
+
+#[test]
+fn test_buy_and_sell_round_in_house_favor() -> Result<(), FinMoneyError> {
+    let quote = eur_usd()?;
+    let euros = FinMoney::new(dec!(100.01), FinMoneyCurrency::EUR);
+
+    // 100.01 * 1.0852 = 108.530852
+    let cost = quote.convert_buy(&euros)?;
+    assert_eq!(cost, FinMoney::new(dec!(108.54), FinMoneyCurrency::USD));
+
+    // 100.01 * 1.0848 = 108.490848
+    let proceeds = quote.convert_sell(&euros)?;
+    assert_eq!(proceeds, FinMoney::new(dec!(108.49), FinMoneyCurrency::USD));
+    Ok(())
+}
+
+#[test]
+fn test_exact_conversions_are_not_rounded() -> Result<(), FinMoneyError> {
+    let quote = FinQuote::new(iso4217::USD, iso4217::JPY, dec!(150), dec!(151))?;
+    let dollars = FinMoney::new(dec!(2), iso4217::USD);
+
+    assert_eq!(quote.convert_buy(&dollars)?.get_amount(), dec!(302));
+    assert_eq!(quote.convert_sell(&dollars)?.get_amount(), dec!(300));
+    Ok(())
+}
+
+#[test]
+fn test_quote_to_base_rounds_in_house_favor() -> Result<(), FinMoneyError> {
+    let quote = eur_usd()?;
+
+    // Spending 500 USD at the ask buys 460.74456... EUR, rounded down
+    let dollars = FinMoney::new(dec!(500), FinMoneyCurrency::USD);
+    let bought = quote.convert_buy(&dollars)?;
+    assert_eq!(bought, FinMoney::new(dec!(460.74), FinMoneyCurrency::EUR));
+
+    // Receiving 500 USD at the bid takes 460.91445... EUR, rounded up
+    let sold = quote.convert_sell(&dollars)?;
+    assert_eq!(sold, FinMoney::new(dec!(460.92), FinMoneyCurrency::EUR));
+    Ok(())
+}
+
+#[test]
+fn test_negative_amounts_round_in_house_favor() -> Result<(), FinMoneyError> {
+    let quote = eur_usd()?;
+    let euros = FinMoney::new(dec!(-100.01), FinMoneyCurrency::EUR);
+    // A refund of 108.530852 USD is rounded down, a reversal of 108.490848 USD owed is rounded up
+    assert_eq!(quote.convert_buy(&euros)?.get_amount(), dec!(-108.53));
+    assert_eq!(quote.convert_sell(&euros)?.get_amount(), dec!(-108.50));
+
+    // -500 / 1.0852 = -460.7445..., -500 / 1.0848 = -460.9144...
+    let dollars = FinMoney::new(dec!(-500), FinMoneyCurrency::USD);
+    assert_eq!(quote.convert_buy(&dollars)?.get_amount(), dec!(-460.75));
+    assert_eq!(quote.convert_sell(&dollars)?.get_amount(), dec!(-460.91));
+
+    // Reversing a trade never returns more than the trade took
+    let bought = quote.convert_buy(&euros.negated())?;
+    assert!(bought.get_amount() >= -quote.convert_buy(&euros)?.get_amount());
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_deserialize_validates() -> Result<(), Box<dyn std::error::Error>> {
+    let quote = eur_usd()?;
+    let json = serde_json::to_value(quote)?;
+    assert_eq!(serde_json::from_value::<FinQuote>(json.clone())?, quote);
+
+    let mut crossed = json.clone();
+    crossed["bid"] = "1.09".into();
+    assert!(serde_json::from_value::<FinQuote>(crossed).is_err());
+
+    let mut negative = json.clone();
+    negative["bid"] = "-1".into();
+    assert!(serde_json::from_value::<FinQuote>(negative).is_err());
+
+    let mut same = json;
+    same["quote"] = same["base"].clone();
+    assert!(serde_json::from_value::<FinQuote>(same).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_conversion_checks_currency() -> Result<(), FinMoneyError> {
+    let quote = eur_usd()?;
+    let bitcoin = FinMoney::new(dec!(1), FinMoneyCurrency::BTC);
+
+    assert!(matches!(
+        quote.convert_buy(&bitcoin),
+        Err(FinMoneyError::CurrencyMismatch { .. })
+    ));
+    assert!(matches!(
+        quote.convert_sell(&bitcoin),
+        Err(FinMoneyError::CurrencyMismatch { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_invalid_quotes() {
+    assert_eq!(
+        FinQuote::new(
+            FinMoneyCurrency::EUR,
+            FinMoneyCurrency::USD,
+            dec!(1.09),
+            dec!(1.08)
+        ),
+        Err(FinMoneyError::CrossedQuote {
+            bid: dec!(1.09),
+            ask: dec!(1.08),
+        })
+    );
+    assert_eq!(
+        FinQuote::new(
+            FinMoneyCurrency::EUR,
+            FinMoneyCurrency::USD,
+            dec!(0),
+            dec!(1.08)
+        ),
+        Err(FinMoneyError::InvalidExchangeRate(dec!(0)))
+    );
+    assert!(matches!(
+        FinQuote::new(
+            FinMoneyCurrency::USD,
+            FinMoneyCurrency::USD,
+            dec!(0.9),
+            dec!(1.1)
+        ),
+        Err(FinMoneyError::InvalidCurrencyPair(_))
+    ));
+}
+
+#[test]
+fn test_display() -> Result<(), FinMoneyError> {
+    assert_eq!(eur_usd()?.to_string(), "EUR/USD 1.0848/1.0852");
+    Ok(())
+}
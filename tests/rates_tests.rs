@@ -0,0 +1,134 @@
+//! Tests for rate providers and cross-rate triangulation.
+
+use finmoney::{
+    FinExchangeRate, FinMoney, FinMoneyBag, FinMoneyCurrency, FinMoneyError,
+    FinMoneyRoundingStrategy, FinRateTable, FinTriangulation, RateProvider, iso4217,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn rate(
+    base: FinMoneyCurrency,
+    quote: FinMoneyCurrency,
+    rate: Decimal,
+) -> Result<FinExchangeRate, FinMoneyError> {
+    FinExchangeRate::new(base, quote, rate)
+}
+
+fn table(triangulation: FinTriangulation) -> Result<FinRateTable, FinMoneyError> {
+    let mut table = FinRateTable::new(triangulation);
+    table.insert(rate(iso4217::EUR, iso4217::USD, dec!(1.08))?);
+    table.insert(rate(iso4217::USD, iso4217::JPY, dec!(150))?);
+    table.insert(rate(iso4217::GBP, iso4217::EUR, dec!(1.16))?);
+    Ok(table)
+}
+
+#[test]
+fn test_direct_and_inverse() -> Result<(), FinMoneyError> {
+    let table = table(FinTriangulation::Direct)?;
+
+    let direct = table.get_rate(iso4217::EUR, iso4217::USD)?;
+    assert_eq!(direct.get_rate().get_rate(), dec!(1.08));
+    assert!(direct.is_direct());
+
+    let inverse = table.get_rate(iso4217::JPY, iso4217::USD)?;
+    assert_eq!(inverse.get_rate().get_base(), iso4217::JPY);
+    assert_eq!(
+        inverse.get_rate().get_rate().round_dp(10),
+        dec!(0.0066666667)
+    );
+    assert_eq!(inverse.get_path(), vec![iso4217::JPY, iso4217::USD]);
+
+    let same = table.get_rate(iso4217::EUR, iso4217::EUR)?;
+    assert_eq!(same.get_rate().get_rate(), dec!(1));
+    assert!(same.get_hops().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_direct_mode_does_not_triangulate() -> Result<(), FinMoneyError> {
+    let table = table(FinTriangulation::Direct)?;
+    assert_eq!(
+        table.get_rate(iso4217::EUR, iso4217::JPY),
+        Err(FinMoneyError::MissingExchangeRate {
+            base: "EUR".to_string(),
+            quote: "JPY".to_string(),
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pivot_triangulation() -> Result<(), FinMoneyError> {
+    let table = table(FinTriangulation::Pivot(iso4217::USD))?;
+
+    let eur_jpy = table.get_rate(iso4217::EUR, iso4217::JPY)?;
+    assert_eq!(eur_jpy.get_rate().get_rate(), dec!(162));
+    assert_eq!(
+        eur_jpy.get_path(),
+        vec![iso4217::EUR, iso4217::USD, iso4217::JPY]
+    );
+    assert!(!eur_jpy.is_direct());
+
+    // GBP has no USD rate, so the pivot cannot reach it.
+    assert!(matches!(
+        table.get_rate(iso4217::GBP, iso4217::JPY),
+        Err(FinMoneyError::MissingExchangeRate { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_shortest_path() -> Result<(), FinMoneyError> {
+    let table = table(FinTriangulation::ShortestPath)?;
+
+    let gbp_jpy = table.get_rate(iso4217::GBP, iso4217::JPY)?;
+    assert_eq!(
+        gbp_jpy.get_path(),
+        vec![iso4217::GBP, iso4217::EUR, iso4217::USD, iso4217::JPY]
+    );
+    // 1.16 * 1.08 * 150
+    assert_eq!(gbp_jpy.get_rate().get_rate(), dec!(187.92));
+
+    assert!(matches!(
+        table.get_rate(iso4217::GBP, iso4217::CHF),
+        Err(FinMoneyError::MissingExchangeRate { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_insert_replaces_rate() -> Result<(), FinMoneyError> {
+    let mut table = table(FinTriangulation::Direct)?;
+    let old = table.insert(rate(iso4217::EUR, iso4217::USD, dec!(1.09))?);
+
+    assert_eq!(old.map(|r| r.get_rate()), Some(dec!(1.08)));
+    assert_eq!(table.len(), 3);
+    assert!(table.remove(iso4217::EUR, iso4217::USD).is_some());
+    assert_eq!(table.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_provider_conversion() -> Result<(), FinMoneyError> {
+    let table = table(FinTriangulation::ShortestPath)?;
+
+    let pounds = FinMoney::new(dec!(10), iso4217::GBP);
+    let yen = table.convert(
+        &pounds,
+        iso4217::JPY,
+        FinMoneyRoundingStrategy::MidpointNearestEven,
+    )?;
+    assert_eq!(yen, FinMoney::new(dec!(1879), iso4217::JPY));
+
+    let mut bag = FinMoneyBag::from(pounds);
+    bag.add(FinMoney::new(dec!(100), iso4217::USD))?;
+    let total = bag.collapse_with(
+        iso4217::EUR,
+        &table,
+        FinMoneyRoundingStrategy::MidpointNearestEven,
+    )?;
+    // 11.60 + 92.592592...
+    assert_eq!(total, FinMoney::new(dec!(104.19), iso4217::EUR));
+    Ok(())
+}
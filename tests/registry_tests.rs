@@ -0,0 +1,146 @@
+//! Tests for the currency registry.
+
+use finmoney::{
+    FinCurrencyKind, FinCurrencyLifecycle, FinCurrencyMetadata, FinDate, FinDenomination,
+    FinMoneyCurrency, FinMoneyCurrencyRegistry, FinMoneyError, iso4217,
+};
+use rust_decimal_macros::dec;
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn test_register_and_lookup() -> Result<(), FinMoneyError> {
+    let registry = FinMoneyCurrencyRegistry::new();
+    assert!(registry.is_empty());
+
+    registry.register(FinMoneyCurrency::USD)?;
+    registry.register(FinMoneyCurrency::BTC)?;
+
+    assert_eq!(registry.len(), 2);
+    assert_eq!(registry.get_by_id(840), Some(FinMoneyCurrency::USD));
+    assert_eq!(registry.get_by_code("BTC"), Some(FinMoneyCurrency::BTC));
+    assert_eq!(registry.get_by_code("ETH"), None);
+    assert_eq!(registry.get_by_id(42), None);
+    assert!(registry.contains(&FinMoneyCurrency::USD));
+    assert!(!registry.contains(&FinMoneyCurrency::EUR));
+
+    Ok(())
+}
+
+#[test]
+fn test_register_identical_is_idempotent() -> Result<(), FinMoneyError> {
+    let registry = FinMoneyCurrencyRegistry::new();
+    registry.register(FinMoneyCurrency::USD)?;
+
+    let unnamed = FinMoneyCurrency::new(840, "USD", None, 2)?;
+    registry.register(unnamed)?;
+
+    assert_eq!(registry.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_register_conflicts() -> Result<(), FinMoneyError> {
+    let registry = FinMoneyCurrencyRegistry::new();
+    registry.register(FinMoneyCurrency::USD)?;
+
+    // Same id, different code
+    let other_code = FinMoneyCurrency::new(840, "EUR", None, 2)?;
+    assert!(matches!(
+        registry.register(other_code),
+        Err(FinMoneyError::CurrencyConflict { .. })
+    ));
+
+    // Same id, different precision
+    let other_precision = FinMoneyCurrency::USD.with_precision(4)?;
+    assert!(matches!(
+        registry.register(other_precision),
+        Err(FinMoneyError::CurrencyConflict { .. })
+    ));
+
+    // Same code, different id
+    let other_id = FinMoneyCurrency::new(99, "USD", None, 2)?;
+    let err = registry.register(other_id).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Currency conflict: USD (id 99, precision 2) conflicts with registered USD (id 840, precision 2)"
+    );
+
+    assert_eq!(registry.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_with_currencies() -> Result<(), FinMoneyError> {
+    let registry = FinMoneyCurrencyRegistry::with_currencies(iso4217::CURRENCIES.iter().copied())?;
+    assert_eq!(registry.len(), iso4217::CURRENCIES.len());
+    assert_eq!(registry.get_by_code("JPY"), Some(iso4217::JPY));
+
+    let ids: Vec<i32> = registry.currencies().iter().map(|c| c.get_id()).collect();
+    let mut sorted = ids.clone();
+    sorted.sort();
+    assert_eq!(ids, sorted);
+
+    let legacy = FinMoneyCurrency::new(1, "USD", None, 2)?;
+    let result = FinMoneyCurrencyRegistry::with_currencies([iso4217::USD, legacy]);
+    assert!(matches!(
+        result,
+        Err(FinMoneyError::CurrencyConflict { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_side_data_requires_the_registered_definition() -> Result<(), FinMoneyError> {
+    let registry = FinMoneyCurrencyRegistry::with_currencies([FinMoneyCurrency::USD])?;
+    let usd = FinMoneyCurrency::USD;
+    registry.set_lifecycle(
+        usd,
+        FinCurrencyLifecycle::new().with_active_from("2000-01-01".parse::<FinDate>()?),
+    )?;
+    registry.set_metadata(usd, FinCurrencyMetadata::new(FinCurrencyKind::Fiat))?;
+    registry.set_unit(usd, FinDenomination::new("cent", dec!(0.01))?)?;
+
+    assert!(registry.get_lifecycle(&usd).is_some());
+    assert!(registry.get_metadata(&usd).is_some());
+    assert_eq!(registry.get_kind(&usd), Some(FinCurrencyKind::Fiat));
+    assert!(registry.get_unit(&usd, "cent").is_some());
+
+    // Same id, different code or precision: none of USD's data applies.
+    for impostor in [
+        FinMoneyCurrency::new(1, "EUR", None, 2)?,
+        usd.with_precision(4)?,
+    ] {
+        assert_eq!(registry.get_lifecycle(&impostor), None);
+        assert_eq!(registry.get_metadata(&impostor), None);
+        assert_eq!(registry.get_kind(&impostor), None);
+        assert_eq!(registry.get_unit(&impostor, "cent"), None);
+        assert!(registry.get_units(&impostor).is_empty());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_registry_shared_between_threads() {
+    let registry = Arc::new(FinMoneyCurrencyRegistry::new());
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let registry = Arc::clone(&registry);
+            thread::spawn(move || {
+                let code = format!("TOK{}", i);
+                let currency = FinMoneyCurrency::new(100 + i, code, None, 8).unwrap();
+                registry.register(currency).unwrap();
+                registry.register(FinMoneyCurrency::USD).unwrap();
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(registry.len(), 5);
+    assert_eq!(registry.get_by_code("TOK3").map(|c| c.get_id()), Some(103));
+}
@@ -0,0 +1,137 @@
+//! Tests for compile-time currency-typed money.
+
+use finmoney::typed::{BTC, TypedMoney, USD};
+use finmoney::{
+    FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyRoundingStrategy, currency_marker, iso4217,
+};
+use rust_decimal_macros::dec;
+
+currency_marker!(pub KWD => iso4217::KWD);
+
+#[test]
+fn test_typed_arithmetic() -> Result<(), FinMoneyError> {
+    let a = TypedMoney::<USD>::new(dec!(10.50));
+    let b = TypedMoney::<USD>::new(dec!(5.25));
+
+    assert_eq!((a + b).get_amount(), dec!(15.75));
+    assert_eq!((a - b).get_amount(), dec!(5.25));
+    assert_eq!((a * dec!(2)).get_amount(), dec!(21.00));
+    assert_eq!((-a).get_amount(), dec!(-10.50));
+
+    let mut total = TypedMoney::<USD>::zero();
+    total += a;
+    total -= a;
+    assert_eq!(total, TypedMoney::zero());
+    total.try_add_assign(a)?;
+    total.try_add_assign(b)?;
+    total.try_sub_assign(b)?;
+    assert_eq!(total, a);
+    assert!(b < a);
+    Ok(())
+}
+
+#[test]
+fn test_typed_precision_and_division() -> Result<(), FinMoneyError> {
+    let amount =
+        TypedMoney::<KWD>::new_with_precision(dec!(1.23456), FinMoneyRoundingStrategy::ToZero);
+    assert_eq!(amount.get_amount(), dec!(1.234));
+
+    let third = TypedMoney::<USD>::new(dec!(10))
+        .divided_by_decimal(dec!(3), FinMoneyRoundingStrategy::MidpointNearestEven)?;
+    assert_eq!(third.get_amount(), dec!(3.33));
+
+    assert!(matches!(
+        third.divided_by_decimal(dec!(0), FinMoneyRoundingStrategy::MidpointNearestEven),
+        Err(FinMoneyError::DivisionByZero)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_conversion_to_and_from_fin_money() -> Result<(), FinMoneyError> {
+    let typed = TypedMoney::<BTC>::new(dec!(0.5));
+    let dynamic: FinMoney = typed.into();
+    assert_eq!(dynamic.get_currency(), FinMoneyCurrency::BTC);
+    assert_eq!(dynamic.get_amount(), dec!(0.5));
+
+    let back = TypedMoney::<BTC>::try_from(dynamic)?;
+    assert_eq!(back, typed);
+
+    let wrong = TypedMoney::<USD>::try_from(dynamic);
+    assert!(matches!(wrong, Err(FinMoneyError::CurrencyMismatch { .. })));
+
+    // Same id but different code is rejected as well.
+    let impostor = FinMoney::new(dec!(1), FinMoneyCurrency::new(840, "EUR", None, 2)?);
+    assert!(TypedMoney::<USD>::try_from(impostor).is_err());
+
+    // So is the same id and code at a different precision.
+    let rescaled = FinMoney::new(dec!(1), FinMoneyCurrency::new(840, "USD", None, 4)?);
+    assert!(matches!(
+        TypedMoney::<USD>::try_from(rescaled),
+        Err(FinMoneyError::CurrencyMismatch { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_typed_display() {
+    let amount = TypedMoney::<USD>::new(dec!(10.50));
+    assert_eq!(amount.to_string(), "10.50 USD");
+    assert_eq!(amount.get_currency(), FinMoneyCurrency::USD);
+}
+
+#[test]
+fn test_typed_checked_arithmetic_reports_overflow() {
+    let huge = TypedMoney::<USD>::new(finmoney::Decimal::MAX);
+    let one = TypedMoney::<USD>::new(dec!(1));
+
+    assert_eq!(
+        huge.checked_add(one),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        (-huge).checked_sub(one),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    assert_eq!(
+        huge.checked_mul_decimal(dec!(2)),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+
+    let mut total = huge;
+    assert_eq!(
+        total.try_add_assign(one),
+        Err(FinMoneyError::ArithmeticOverflow)
+    );
+    assert_eq!(total, huge);
+}
+
+#[test]
+fn test_typed_saturating_arithmetic() {
+    let max = TypedMoney::<USD>::new(FinMoney::max_value(FinMoneyCurrency::USD).get_amount());
+    let one = TypedMoney::<USD>::new(dec!(1));
+
+    assert_eq!(max.saturating_add(max), max);
+    assert_eq!((-max).saturating_sub(max), -max);
+    assert_eq!(max.saturating_mul_decimal(dec!(-2)), -max);
+    assert_eq!(one.saturating_add(one), TypedMoney::new(dec!(2)));
+
+    // An amount already past the bound is never pulled back
+    let huge = TypedMoney::<USD>::new(finmoney::Decimal::MAX);
+    assert_eq!(huge.saturating_add(one), huge);
+    assert_eq!(huge.saturating_mul_decimal(dec!(1.0000001)), huge);
+}
+
+#[test]
+#[should_panic(expected = "TypedMoney addition overflowed")]
+fn test_typed_add_panics_on_overflow() {
+    let huge = TypedMoney::<USD>::new(finmoney::Decimal::MAX);
+    let _ = huge + TypedMoney::new(dec!(1));
+}
+
+#[test]
+#[should_panic(expected = "TypedMoney subtraction overflowed")]
+fn test_typed_sub_assign_panics_on_overflow() {
+    let mut total = TypedMoney::<USD>::new(finmoney::Decimal::MIN);
+    total -= TypedMoney::new(dec!(1));
+}
@@ -0,0 +1,267 @@
+//! Tests for waterfall distributions.
+
+use finmoney::{
+    FinMoney, FinMoneyCurrency, FinMoneyError, FinMoneyIteratorExt, FinMoneyWaterfall,
+    FinMoneyWaterfallTier,
+};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn usd(amount: Decimal) -> FinMoney {
+    FinMoney::new(amount, FinMoneyCurrency::USD)
+}
+
+fn fund_waterfall() -> FinMoneyWaterfall {
+    FinMoneyWaterfall::new()
+        .with_tier(
+            FinMoneyWaterfallTier::new("return of capital", 1)
+                .with_cap(usd(dec!(1000)))
+                .with_split("LP", dec!(0.9))
+                .with_split("GP", dec!(0.1)),
+        )
+        .with_tier(
+            FinMoneyWaterfallTier::new("preferred return", 2)
+                .with_cap(usd(dec!(80)))
+                .with_split("LP", dec!(0.9))
+                .with_split("GP", dec!(0.1)),
+        )
+        .with_tier(
+            FinMoneyWaterfallTier::new("catch-up", 3)
+                .with_cap(usd(dec!(20)))
+                .with_split("GP", dec!(1)),
+        )
+        .with_tier(
+            FinMoneyWaterfallTier::new("carried interest", 4)
+                .with_split("LP", dec!(80))
+                .with_split("GP", dec!(20)),
+        )
+}
+
+#[test]
+fn test_full_waterfall() -> Result<(), FinMoneyError> {
+    let result = fund_waterfall().distribute(usd(dec!(1600)))?;
+
+    // LP: 900 + 72 + 400, GP: 100 + 8 + 20 + 100
+    assert_eq!(result.get_allocation("LP"), Some(usd(dec!(1372))));
+    assert_eq!(result.get_allocation("GP"), Some(usd(dec!(228))));
+    assert!(result.get_remainder().is_zero());
+
+    let tiers: Vec<_> = result
+        .get_tier_totals()
+        .iter()
+        .map(|(name, amount)| (name.as_str(), amount.get_amount()))
+        .collect();
+    assert_eq!(
+        tiers,
+        vec![
+            ("return of capital", dec!(1000)),
+            ("preferred return", dec!(80)),
+            ("catch-up", dec!(20)),
+            ("carried interest", dec!(500)),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_partial_fill_stops_at_cap() -> Result<(), FinMoneyError> {
+    let result = fund_waterfall().distribute(usd(dec!(1050)))?;
+
+    assert_eq!(result.get_allocation("LP"), Some(usd(dec!(945))));
+    assert_eq!(result.get_allocation("GP"), Some(usd(dec!(105))));
+    assert_eq!(result.get_tier_totals()[1].1, usd(dec!(50)));
+    assert!(result.get_tier_totals()[3].1.is_zero());
+    Ok(())
+}
+
+#[test]
+fn test_priority_orders_tiers() -> Result<(), FinMoneyError> {
+    let waterfall = FinMoneyWaterfall::new()
+        .with_tier(FinMoneyWaterfallTier::new("residual", 9).with_split("B", dec!(1)))
+        .with_tier(
+            FinMoneyWaterfallTier::new("senior", 1)
+                .with_cap(usd(dec!(10)))
+                .with_split("A", dec!(1)),
+        );
+
+    let result = waterfall.distribute(usd(dec!(25)))?;
+    assert_eq!(result.get_allocation("A"), Some(usd(dec!(10))));
+    assert_eq!(result.get_allocation("B"), Some(usd(dec!(15))));
+    assert_eq!(result.get_allocations()[0].0, "A");
+    Ok(())
+}
+
+#[test]
+fn test_remainder_when_all_tiers_capped() -> Result<(), FinMoneyError> {
+    let waterfall = FinMoneyWaterfall::new().with_tier(
+        FinMoneyWaterfallTier::new("only", 1)
+            .with_cap(usd(dec!(10)))
+            .with_split("A", dec!(1)),
+    );
+
+    let result = waterfall.distribute(usd(dec!(12.34)))?;
+    assert_eq!(result.get_remainder(), usd(dec!(2.34)));
+    Ok(())
+}
+
+#[test]
+fn test_missed_hurdle_holds_back_the_rest() -> Result<(), FinMoneyError> {
+    let waterfall = FinMoneyWaterfall::new()
+        .with_tier(
+            FinMoneyWaterfallTier::new("performance fee", 1)
+                .with_hurdle(usd(dec!(100)))
+                .with_cap(usd(dec!(5)))
+                .with_split("Manager", dec!(1)),
+        )
+        .with_tier(FinMoneyWaterfallTier::new("investors", 2).with_split("Investor", dec!(1)));
+
+    let below = waterfall.distribute(usd(dec!(99.99)))?;
+    assert_eq!(below.get_allocation("Manager"), Some(usd(dec!(0))));
+    assert_eq!(below.get_allocation("Investor"), Some(usd(dec!(0))));
+    assert_eq!(below.get_remainder(), usd(dec!(99.99)));
+
+    let above = waterfall.distribute(usd(dec!(100)))?;
+    assert_eq!(above.get_allocation("Manager"), Some(usd(dec!(5))));
+    assert_eq!(above.get_allocation("Investor"), Some(usd(dec!(95))));
+    Ok(())
+}
+
+#[test]
+fn test_missed_senior_hurdle_gates_junior_tiers() -> Result<(), FinMoneyError> {
+    let waterfall = FinMoneyWaterfall::new()
+        .with_tier(
+            FinMoneyWaterfallTier::new("return of capital", 1)
+                .with_cap(usd(dec!(40)))
+                .with_split("LP", dec!(1)),
+        )
+        .with_tier(
+            FinMoneyWaterfallTier::new("pref", 2)
+                .with_hurdle(usd(dec!(100)))
+                .with_cap(usd(dec!(50)))
+                .with_split("LP", dec!(1)),
+        )
+        .with_tier(FinMoneyWaterfallTier::new("carry", 3).with_split("GP", dec!(1)));
+
+    let result = waterfall.distribute(usd(dec!(99)))?;
+    assert_eq!(result.get_allocation("LP"), Some(usd(dec!(40))));
+    assert_eq!(result.get_allocation("GP"), Some(usd(dec!(0))));
+    assert_eq!(
+        result.get_tier_totals(),
+        &[
+            ("return of capital".to_string(), usd(dec!(40))),
+            ("pref".to_string(), usd(dec!(0))),
+            ("carry".to_string(), usd(dec!(0))),
+        ]
+    );
+    assert_eq!(result.get_remainder(), usd(dec!(59)));
+    Ok(())
+}
+
+#[test]
+fn test_exact_to_precision() -> Result<(), FinMoneyError> {
+    let waterfall = FinMoneyWaterfall::new().with_tier(
+        FinMoneyWaterfallTier::new("split", 1)
+            .with_split("A", dec!(1))
+            .with_split("B", dec!(1))
+            .with_split("C", dec!(1)),
+    );
+
+    let total = usd(dec!(100.00));
+    let result = waterfall.distribute(total)?;
+    let sum = result
+        .get_allocations()
+        .iter()
+        .map(|(_, amount)| amount)
+        .try_sum()?;
+    assert_eq!(sum, Some(total));
+    assert_eq!(result.get_allocation("A"), Some(usd(dec!(33.34))));
+    Ok(())
+}
+
+#[test]
+fn test_waterfall_errors() {
+    let no_parties = FinMoneyWaterfall::new().with_tier(FinMoneyWaterfallTier::new("empty", 1));
+    assert!(matches!(
+        no_parties.distribute(usd(dec!(1))),
+        Err(FinMoneyError::InvalidAllocation(_))
+    ));
+
+    assert!(matches!(
+        fund_waterfall().distribute(usd(dec!(-1))),
+        Err(FinMoneyError::InvalidAmount(_))
+    ));
+
+    assert!(matches!(
+        fund_waterfall().distribute(FinMoney::new(dec!(10), FinMoneyCurrency::EUR)),
+        Err(FinMoneyError::CurrencyMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_skipped_tiers_are_validated() {
+    // The negative cap sits on a tier that the hurdle skips and that nothing reaches.
+    let skipped = FinMoneyWaterfall::new()
+        .with_tier(FinMoneyWaterfallTier::new("everything", 1).with_split("A", dec!(1)))
+        .with_tier(
+            FinMoneyWaterfallTier::new("bonus", 2)
+                .with_hurdle(usd(dec!(1000)))
+                .with_cap(usd(dec!(-5)))
+                .with_split("B", dec!(1)),
+        );
+    assert!(matches!(
+        skipped.distribute(usd(dec!(10))),
+        Err(FinMoneyError::InvalidAllocation(_))
+    ));
+
+    let negative_hurdle = FinMoneyWaterfall::new().with_tier(
+        FinMoneyWaterfallTier::new("fee", 1)
+            .with_hurdle(usd(dec!(-1)))
+            .with_split("A", dec!(1)),
+    );
+    assert!(matches!(
+        negative_hurdle.distribute(usd(dec!(10))),
+        Err(FinMoneyError::InvalidAllocation(_))
+    ));
+
+    let bad_weights = FinMoneyWaterfall::new()
+        .with_tier(FinMoneyWaterfallTier::new("everything", 1).with_split("A", dec!(1)))
+        .with_tier(
+            FinMoneyWaterfallTier::new("unreached", 2)
+                .with_split("B", dec!(1))
+                .with_split("C", dec!(-1)),
+        );
+    assert!(matches!(
+        bad_weights.distribute(usd(dec!(10))),
+        Err(FinMoneyError::InvalidAllocation(_))
+    ));
+
+    let foreign_hurdle = FinMoneyWaterfall::new().with_tier(
+        FinMoneyWaterfallTier::new("fee", 1)
+            .with_hurdle(FinMoney::new(dec!(1), FinMoneyCurrency::EUR))
+            .with_split("A", dec!(1)),
+    );
+    assert!(matches!(
+        foreign_hurdle.distribute(usd(dec!(10))),
+        Err(FinMoneyError::CurrencyMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_hurdle_measures_whole_total() -> Result<(), FinMoneyError> {
+    // Only 20 is left when the fee tier is reached, but the hurdle looks at the full 120.
+    let waterfall = FinMoneyWaterfall::new()
+        .with_tier(
+            FinMoneyWaterfallTier::new("capital", 1)
+                .with_cap(usd(dec!(100)))
+                .with_split("Investor", dec!(1)),
+        )
+        .with_tier(
+            FinMoneyWaterfallTier::new("fee", 2)
+                .with_hurdle(usd(dec!(110)))
+                .with_split("Manager", dec!(1)),
+        );
+
+    let result = waterfall.distribute(usd(dec!(120)))?;
+    assert_eq!(result.get_allocation("Manager"), Some(usd(dec!(20))));
+    Ok(())
+}